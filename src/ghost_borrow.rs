@@ -3,12 +3,32 @@
 //!
 //! Technically, this is already allowed, however it can be useful to do so as a single expression, which this trait
 //! provides.
+//!
+//! It also provides free functions borrowing a slice of `GhostCell`s, such as `fold`, `try_fold`, `hash_all`,
+//! `binary_search`, `chunks`, `position`, `find`, `any`, `all`, `min_by_key`, `max_by_key` and `index_by`, as well as
+//! `slice`, `borrow_iter` and `try_borrow_slice_as_array`.
 
+use core::array;
+use core::hash::{Hash, Hasher};
 use core::mem;
+use core::ops::ControlFlow;
 use core::ptr;
+use core::slice::SliceIndex;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use crate::ghost_cell::*;
 
+/// An error signifying that a slice of `GhostCell`s does not have the expected length.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct GhostLengthError {
+    /// The expected length.
+    pub expected: usize,
+    /// The actual length of the slice.
+    pub actual: usize,
+}
+
 /// A trait for implementing multiple borrows for any number of arguments, using a `GhostToken<'a, 'brand>`.
 ///
 /// Implemented for a mixture of tuple and array types.
@@ -128,6 +148,461 @@ generate_public_instance!(a, b, c, d, e, f, g, h, i, j ; T0, T1, T2, T3, T4, T5,
 generate_public_instance!(a, b, c, d, e, f, g, h, i, j, k ; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, TA);
 generate_public_instance!(a, b, c, d, e, f, g, h, i, j, k, l ; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, TA, TB);
 
+//
+//  Slice helpers
+//
+
+/// Folds every element of a slice of `GhostCell`s, in order, with the same-branded token.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// let value = GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3)];
+///
+///     ghost_borrow::fold(&cells, 0, &token, |acc, x| acc + x)
+/// });
+///
+/// assert_eq!(6, value);
+/// ```
+pub fn fold<'a, 'brand, T, Acc, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    init: Acc,
+    token: &'a GhostToken<'brand>,
+    f: F,
+) -> Acc
+where
+    F: FnMut(Acc, &'a T) -> Acc,
+{
+    cells.borrow(token).iter().fold(init, f)
+}
+
+/// Folds the elements of a slice of `GhostCell`s, in order, with the same-branded token, stopping as soon as `f`
+/// returns `ControlFlow::Break`.
+///
+/// #   Example
+///
+/// ```rust
+/// use core::ops::ControlFlow;
+///
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// let value = GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(5), GhostCell::new(3)];
+///
+///     ghost_borrow::try_fold(&cells, 0, &token, |acc, x| {
+///         if *x > 4 { ControlFlow::Break(*x) } else { ControlFlow::Continue(acc + x) }
+///     })
+/// });
+///
+/// assert_eq!(ControlFlow::Break(5), value);
+/// ```
+pub fn try_fold<'a, 'brand, T, Acc, B, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    init: Acc,
+    token: &'a GhostToken<'brand>,
+    f: F,
+) -> ControlFlow<B, Acc>
+where
+    F: FnMut(Acc, &'a T) -> ControlFlow<B, Acc>,
+{
+    cells.borrow(token).iter().try_fold(init, f)
+}
+
+/// Feeds every element of a slice of `GhostCell`s, in order, into `state`.
+///
+/// #   Example
+///
+/// ```rust
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|mut token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3)];
+///
+///     let mut before = DefaultHasher::new();
+///     ghost_borrow::hash_all(&cells, &token, &mut before);
+///
+///     *cells[1].borrow_mut(&mut token) = 4;
+///
+///     let mut after = DefaultHasher::new();
+///     ghost_borrow::hash_all(&cells, &token, &mut after);
+///
+///     assert_ne!(before.finish(), after.finish());
+/// });
+/// ```
+pub fn hash_all<'brand, T, H>(cells: &[GhostCell<'brand, T>], token: &GhostToken<'brand>, state: &mut H)
+where
+    T: Hash,
+    H: Hasher,
+{
+    T::hash_slice(cells.borrow(token), state);
+}
+
+/// Binary searches a sorted slice of `GhostCell`s for `target`.
+///
+/// See `slice::binary_search` for details.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(3), GhostCell::new(5)];
+///
+///     assert_eq!(Ok(1), ghost_borrow::binary_search(&cells, &3, &token));
+///     assert_eq!(Err(2), ghost_borrow::binary_search(&cells, &4, &token));
+/// });
+/// ```
+pub fn binary_search<'brand, T>(
+    cells: &[GhostCell<'brand, T>],
+    target: &T,
+    token: &GhostToken<'brand>,
+) -> Result<usize, usize>
+where
+    T: Ord,
+{
+    cells.borrow(token).binary_search(target)
+}
+
+/// Returns the sub-slice of `cells` covered by `range`, or `None` if `range` is out of bounds.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostBorrow, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3)];
+///
+///     let middle = ghost_borrow::slice(&cells, 1..).expect("in bounds");
+///     assert_eq!(&[2, 3], middle.borrow(&token));
+///
+///     assert!(ghost_borrow::slice(&cells, 2..4).is_none());
+/// });
+/// ```
+pub fn slice<'a, 'brand, T, R>(cells: &'a [GhostCell<'brand, T>], range: R) -> Option<&'a [GhostCell<'brand, T>]>
+where
+    R: SliceIndex<[GhostCell<'brand, T>], Output = [GhostCell<'brand, T>]>,
+{
+    cells.get(range)
+}
+
+/// Borrows an array of references to `GhostCell`s, and returns an iterator over the borrowed values.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// let value = GhostToken::new(|token| {
+///     let (one, two, three) = (GhostCell::new(1), GhostCell::new(2), GhostCell::new(3));
+///
+///     ghost_borrow::borrow_iter([&one, &two, &three], &token).sum::<i32>()
+/// });
+///
+/// assert_eq!(6, value);
+/// ```
+pub fn borrow_iter<'a, 'brand, T: ?Sized, const N: usize>(
+    cells: [&'a GhostCell<'brand, T>; N],
+    token: &'a GhostToken<'brand>,
+) -> array::IntoIter<&'a T, N> {
+    cells.borrow(token).into_iter()
+}
+
+/// Borrows a slice of `GhostCell`s as an array of `N` references, if the slice has exactly `N` elements.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostLengthError, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells: Vec<_> = (1..=3).map(GhostCell::new).collect();
+///
+///     let [x, y, z] = ghost_borrow::try_borrow_slice_as_array(&cells, &token).unwrap();
+///     assert_eq!((1, 2, 3), (*x, *y, *z));
+///
+///     let result = ghost_borrow::try_borrow_slice_as_array::<_, 2>(&cells, &token);
+///     assert_eq!(Err(GhostLengthError { expected: 2, actual: 3 }), result);
+/// });
+/// ```
+pub fn try_borrow_slice_as_array<'a, 'brand, T, const N: usize>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+) -> Result<[&'a T; N], GhostLengthError> {
+    let array: &'a [GhostCell<'brand, T>; N] =
+        cells.try_into().map_err(|_| GhostLengthError { expected: N, actual: cells.len() })?;
+
+    Ok(array.borrow(token).each_ref())
+}
+
+/// Returns the index of, and a reference to, the element of a slice of `GhostCell`s with the minimum key, or `None`
+/// if the slice is empty.
+///
+/// If several elements are equally minimum, the first one is returned.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(-3), GhostCell::new(1), GhostCell::new(2)];
+///
+///     assert_eq!(Some((1, &1)), ghost_borrow::min_by_key(&cells, &token, |x: &i32| x.abs()));
+/// });
+/// ```
+pub fn min_by_key<'a, 'brand, T, K, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+    mut key: F,
+) -> Option<(usize, &'a T)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    cells.borrow(token).iter().enumerate().min_by_key(|(_, value)| key(value))
+}
+
+/// Returns the index of, and a reference to, the element of a slice of `GhostCell`s with the maximum key, or `None`
+/// if the slice is empty.
+///
+/// If several elements are equally maximum, the last one is returned.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(-3), GhostCell::new(1), GhostCell::new(2)];
+///
+///     assert_eq!(Some((0, &-3)), ghost_borrow::max_by_key(&cells, &token, |x: &i32| x.abs()));
+/// });
+/// ```
+pub fn max_by_key<'a, 'brand, T, K, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+    mut key: F,
+) -> Option<(usize, &'a T)>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    cells.borrow(token).iter().enumerate().max_by_key(|(_, value)| key(value))
+}
+
+/// Returns references to the elements of a slice of `GhostCell`s matching `predicate`, in order.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3)];
+///
+///     assert_eq!(vec![&1, &3], ghost_borrow::filter_refs(&cells, &token, |x| x % 2 == 1));
+/// });
+/// ```
+#[cfg(feature = "alloc")]
+pub fn filter_refs<'a, 'brand, T, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+    predicate: F,
+) -> Vec<&'a T>
+where
+    F: Fn(&T) -> bool,
+{
+    cells.borrow(token).iter().filter(|value| predicate(value)).collect()
+}
+
+/// Returns an iterator over the elements of a slice of `GhostCell`s, in chunks of `size` elements.
+///
+/// The last chunk is shorter if `size` does not divide the length of `cells`. See `slice::chunks` for details.
+///
+/// #   Panics
+///
+/// Panics if `size` is 0.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3), GhostCell::new(4), GhostCell::new(5)];
+///
+///     let mut chunks = ghost_borrow::chunks(&cells, 2, &token);
+///
+///     assert_eq!(Some(&[1, 2][..]), chunks.next());
+///     assert_eq!(Some(&[3, 4][..]), chunks.next());
+///     assert_eq!(Some(&[5][..]), chunks.next());
+///     assert_eq!(None, chunks.next());
+/// });
+/// ```
+pub fn chunks<'a, 'brand, T>(
+    cells: &'a [GhostCell<'brand, T>],
+    size: usize,
+    token: &'a GhostToken<'brand>,
+) -> core::slice::Chunks<'a, T> {
+    cells.borrow(token).chunks(size)
+}
+
+/// Returns an iterator over the elements of a slice of `GhostCell`s, along with their index.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new('a'), GhostCell::new('b')];
+///
+///     let mut pairs = ghost_borrow::enumerate_borrow(&cells, &token);
+///
+///     assert_eq!(Some((0, &'a')), pairs.next());
+///     assert_eq!(Some((1, &'b')), pairs.next());
+///     assert_eq!(None, pairs.next());
+/// });
+/// ```
+pub fn enumerate_borrow<'a, 'brand, T>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+) -> core::iter::Enumerate<core::slice::Iter<'a, T>> {
+    cells.borrow(token).iter().enumerate()
+}
+
+/// Returns the index of the first element of a slice of `GhostCell`s matching `predicate`, if any.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(4), GhostCell::new(9)];
+///
+///     assert_eq!(Some(1), ghost_borrow::position(&cells, &token, |x| x % 2 == 0));
+///     assert_eq!(None, ghost_borrow::position(&cells, &token, |x| *x > 10));
+/// });
+/// ```
+pub fn position<'brand, T, F>(cells: &[GhostCell<'brand, T>], token: &GhostToken<'brand>, predicate: F) -> Option<usize>
+where
+    F: Fn(&T) -> bool,
+{
+    cells.borrow(token).iter().position(predicate)
+}
+
+/// Returns a reference to the first element of a slice of `GhostCell`s matching `predicate`, if any.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new("ghost"), GhostCell::new("cell"), GhostCell::new("token")];
+///
+///     assert_eq!(Some(&"cell"), ghost_borrow::find(&cells, &token, |s| s.starts_with('c')));
+///     assert_eq!(None, ghost_borrow::find(&cells, &token, |s| s.is_empty()));
+/// });
+/// ```
+pub fn find<'a, 'brand, T, F>(
+    cells: &'a [GhostCell<'brand, T>],
+    token: &'a GhostToken<'brand>,
+    predicate: F,
+) -> Option<&'a T>
+where
+    F: Fn(&T) -> bool,
+{
+    cells.borrow(token).iter().find(|value| predicate(value))
+}
+
+/// Returns whether any element of a slice of `GhostCell`s matches `predicate`.
+///
+/// Returns `false` if `cells` is empty.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(4), GhostCell::new(9)];
+///
+///     assert!(ghost_borrow::any(&cells, &token, |x| x % 2 == 0));
+///     assert!(!ghost_borrow::any(&cells, &token, |x| *x > 10));
+/// });
+/// ```
+pub fn any<'brand, T, F>(cells: &[GhostCell<'brand, T>], token: &GhostToken<'brand>, predicate: F) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    cells.borrow(token).iter().any(predicate)
+}
+
+/// Returns whether all elements of a slice of `GhostCell`s match `predicate`.
+///
+/// Returns `true` if `cells` is empty.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(4), GhostCell::new(9)];
+///
+///     assert!(ghost_borrow::all(&cells, &token, |x| *x > 0));
+///     assert!(!ghost_borrow::all(&cells, &token, |x| x % 2 == 1));
+/// });
+/// ```
+pub fn all<'brand, T, F>(cells: &[GhostCell<'brand, T>], token: &GhostToken<'brand>, predicate: F) -> bool
+where
+    F: Fn(&T) -> bool,
+{
+    cells.borrow(token).iter().all(predicate)
+}
+
+/// Returns an index of a slice of `GhostCell`s, mapping the key of each element to its position in the slice.
+///
+/// If several elements have the same key, the position of the last one is kept.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_borrow};
+///
+/// GhostToken::new(|token| {
+///     let cells = [GhostCell::new(("ghost", 1)), GhostCell::new(("cell", 2)), GhostCell::new(("ghost", 3))];
+///
+///     let index = ghost_borrow::index_by(&cells, &token, |entry| entry.0);
+///
+///     assert_eq!(2, index.len());
+///     assert_eq!(Some(&1), index.get("cell"));
+///     assert_eq!(Some(&2), index.get("ghost"));
+/// });
+/// ```
+#[cfg(feature = "alloc")]
+pub fn index_by<'brand, T, K, F>(
+    cells: &[GhostCell<'brand, T>],
+    token: &GhostToken<'brand>,
+    key: F,
+) -> BTreeMap<K, usize>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    cells.borrow(token).iter().enumerate().map(|(index, value)| (key(value), index)).collect()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -243,4 +718,228 @@ fn multiple_borrows_array_unsized_dyn_trait() {
     assert_eq!(["42".to_owned(), "47".to_owned(), "7".to_owned(), "9".to_owned()], value);
 }
 
+#[test]
+fn fold_slice() {
+    let value = GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        fold(&cells, 0, &token, |acc, x| acc + x)
+    });
+    assert_eq!(15, value);
+}
+
+#[test]
+fn try_fold_slice() {
+    let (value, visited) = GhostToken::new(|token| {
+        let cells: Vec<_> = [1, 2, 7, 3, 9].into_iter().map(GhostCell::new).collect();
+        let mut visited = 0;
+
+        let value = try_fold(&cells, 0, &token, |sum, x| {
+            visited += 1;
+
+            if *x > 5 { ControlFlow::Break((sum, *x)) } else { ControlFlow::Continue(sum + x) }
+        });
+
+        (value, visited)
+    });
+    assert_eq!(ControlFlow::Break((3, 7)), value);
+    assert_eq!(3, visited);
+}
+
+#[test]
+fn try_fold_slice_exhausted() {
+    let value = GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        try_fold(&cells, 0, &token, |sum, x| {
+            if *x > 5 { ControlFlow::Break(*x) } else { ControlFlow::Continue(sum + x) }
+        })
+    });
+    assert_eq!(ControlFlow::<i32, i32>::Continue(15), value);
+}
+
+#[test]
+fn hash_all_slice() {
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash<'brand>(cells: &[GhostCell<'brand, i32>], token: &GhostToken<'brand>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hash_all(cells, token, &mut hasher);
+        hasher.finish()
+    }
+
+    GhostToken::new(|mut token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        let first = hash(&cells, &token);
+        assert_eq!(first, hash(&cells, &token));
+
+        *cells[2].borrow_mut(&mut token) = 42;
+
+        let second = hash(&cells, &token);
+        assert_ne!(first, second);
+        assert_eq!(second, hash(&cells, &token));
+    });
+}
+
+#[test]
+fn binary_search_slice() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [2, 4, 8, 16, 32].into_iter().map(GhostCell::new).collect();
+
+        assert_eq!(Ok(3), binary_search(&cells, &16, &token));
+        assert_eq!(Err(0), binary_search(&cells, &1, &token));
+        assert_eq!(Err(3), binary_search(&cells, &10, &token));
+        assert_eq!(Err(5), binary_search(&cells, &64, &token));
+    });
+}
+
+#[test]
+fn slice_then_borrow() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        let sub = slice(&cells, 1..4).expect("in bounds");
+        assert_eq!(&[2, 3, 4], sub.borrow(&token));
+
+        let empty = slice(&cells, 5..).expect("in bounds");
+        assert!(empty.borrow(&token).is_empty());
+
+        assert!(slice(&cells, 3..6).is_none());
+    });
+}
+
+#[test]
+fn borrow_iter_array() {
+    let value = GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        borrow_iter([&cells[4], &cells[0], &cells[2]], &token).sum::<i32>()
+    });
+    assert_eq!(9, value);
+}
+
+#[test]
+fn try_borrow_slice_as_array_lengths() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = ["x", "y"].into_iter().map(GhostCell::new).collect();
+
+        assert_eq!(Ok([&"x", &"y"]), try_borrow_slice_as_array(&cells, &token));
+
+        assert_eq!(
+            Err(GhostLengthError { expected: 3, actual: 2 }),
+            try_borrow_slice_as_array::<_, 3>(&cells, &token)
+        );
+        assert_eq!(
+            Err(GhostLengthError { expected: 1, actual: 2 }),
+            try_borrow_slice_as_array::<_, 1>(&cells, &token)
+        );
+
+        assert_eq!(Ok([]), try_borrow_slice_as_array::<_, 0>(&cells[..0], &token));
+    });
+}
+
+#[test]
+fn min_max_by_key_slice() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [4, 9, 2, 9, 7].into_iter().map(GhostCell::new).collect();
+
+        assert_eq!(Some((3, &9)), max_by_key(&cells, &token, |x| *x));
+        assert_eq!(Some((2, &2)), min_by_key(&cells, &token, |x| *x));
+        assert_eq!(Some((1, &9)), min_by_key(&cells, &token, |x| core::cmp::Reverse(*x)));
+
+        let empty: [GhostCell<i32>; 0] = [];
+        assert_eq!(None, max_by_key(&empty, &token, |x| *x));
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn filter_refs_even() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=10).map(GhostCell::new).collect();
+
+        let even = filter_refs(&cells, &token, |x| x % 2 == 0);
+
+        assert_eq!(vec![&2, &4, &6, &8, &10], even);
+        assert!(filter_refs(&cells, &token, |x| *x > 10).is_empty());
+    });
+}
+
+#[test]
+fn chunks_sum() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = (1..=10).map(GhostCell::new).collect();
+
+        let sums: Vec<i32> = chunks(&cells, 3, &token).map(|chunk| chunk.iter().sum()).collect();
+
+        assert_eq!(vec![6, 15, 24, 10], sums);
+    });
+}
+
+#[test]
+fn enumerate_borrow_pairs() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [10, 20, 30].into_iter().map(GhostCell::new).collect();
+
+        let pairs: Vec<(usize, &i32)> = enumerate_borrow(&cells, &token).collect();
+
+        assert_eq!(vec![(0, &10), (1, &20), (2, &30)], pairs);
+    });
+}
+
+#[test]
+fn position_find_first_match() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [3, 8, 5, 12, 7].into_iter().map(GhostCell::new).collect();
+
+        assert_eq!(Some(1), position(&cells, &token, |x| *x > 5));
+        assert_eq!(Some(&8), find(&cells, &token, |x| *x > 5));
+
+        assert_eq!(None, position(&cells, &token, |x| *x > 12));
+        assert_eq!(None, find(&cells, &token, |x| *x > 12));
+    });
+}
+
+#[test]
+fn any_all_some_all_none() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [2, 4, 5, 8].into_iter().map(GhostCell::new).collect();
+
+        //  Some match.
+        assert!(any(&cells, &token, |x| x % 2 == 1));
+        assert!(!all(&cells, &token, |x| x % 2 == 1));
+
+        //  All match.
+        assert!(any(&cells, &token, |x| *x > 0));
+        assert!(all(&cells, &token, |x| *x > 0));
+
+        //  None match.
+        assert!(!any(&cells, &token, |x| *x > 8));
+        assert!(!all(&cells, &token, |x| *x > 8));
+
+        //  Empty.
+        assert!(!any(&cells[..0], &token, |_| true));
+        assert!(all(&cells[..0], &token, |_| false));
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn index_by_lookup() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = ["apple", "banana", "cherry", "avocado"].into_iter().map(GhostCell::new).collect();
+
+        let by_name = index_by(&cells, &token, |name| *name);
+        assert_eq!(Some(&2), by_name.get("cherry"));
+        assert_eq!(None, by_name.get("durian"));
+
+        //  "apple" and "avocado" share their initial, the last one wins.
+        let by_initial = index_by(&cells, &token, |name| name.chars().next());
+        assert_eq!(3, by_initial.len());
+        assert_eq!(Some(&3), by_initial.get(&Some('a')));
+        assert_eq!(Some(&1), by_initial.get(&Some('b')));
+    });
+}
+
 } // mod tests
\ No newline at end of file
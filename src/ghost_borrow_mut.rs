@@ -8,10 +8,15 @@
 //! -   A tuple of `GhostCell`s of up to 12 elements.
 //! -   A tuple of references to `GhostCell`s of up to 12 elements.
 //!
+//! It also provides `borrow_many_mut`, which mutably borrows multiple `GhostCell`s of a slice by index.
+//!
+//! Finally, it provides convenience methods requiring multiple mutable borrows, such as `GhostCell::append`.
+//!
 //! #   Performance
 //!
 //! In general borrowing is free of cost, however a special-case is necessary for the tuple of references, as then the
-//! references may alias.
+//! references may alias. The check sorts the addresses of the cells in a fixed-size array then compares adjacent
+//! addresses, hence runs in O(n log n) without allocating, and may be skipped with `borrow_mut_unchecked`.
 //!
 //! #   Experimental
 //!
@@ -19,6 +24,9 @@
 
 use core::{convert::Infallible, mem, ptr};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use crate::ghost_cell::*;
 
 /// An error signifying that two `GhostCell`s that need to be distinct were actually the same cell.
@@ -34,6 +42,19 @@ impl From<Infallible> for GhostAliasingError {
     }
 }
 
+/// An error signifying that indices into a slice of `GhostCell`s are invalid.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum GhostIndexError {
+    /// One of the indices is out of bounds.
+    OutOfBounds,
+    /// Two of the indices are equal, and would therefore borrow the same `GhostCell` twice.
+    Aliasing,
+}
+
+impl From<GhostAliasingError> for GhostIndexError {
+    fn from(_: GhostAliasingError) -> Self { GhostIndexError::Aliasing }
+}
+
 /// A trait for implementing multiple borrows for any number of arguments, using a `GhostToken<'a, 'brand>`.
 ///
 /// Implemented for a mixture of tuple and array types.
@@ -235,6 +256,107 @@ generate_public_instance!(a, b, c, d, e, f, g, h, i, j ; T0, T1, T2, T3, T4, T5,
 generate_public_instance!(a, b, c, d, e, f, g, h, i, j, k ; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, TA);
 generate_public_instance!(a, b, c, d, e, f, g, h, i, j, k, l ; T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, TA, TB);
 
+/// Mutably borrows the `GhostCell`s of `cells` at `indices` at the same time.
+///
+/// The references are returned in the order of `indices`, regardless of the order of the indices themselves.
+///
+/// Returns `GhostIndexError::OutOfBounds` if any index is out of bounds, and `GhostIndexError::Aliasing` if any index
+/// is repeated.
+///
+/// #   Performance
+///
+/// Checking the indices requires sorting a copy of them, with no allocation. If performance matters and the caller is
+/// certain the indices are distinct and in bounds, `borrow_many_mut_unchecked` may be used instead.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostIndexError, ghost_borrow_mut};
+///
+/// GhostToken::new(|mut token| {
+///     let cells: Vec<_> = (0..10).map(GhostCell::new).collect();
+///
+///     let [a, b, c] = ghost_borrow_mut::borrow_many_mut(&cells, [2, 5, 9], &mut token).unwrap();
+///     std::mem::swap(a, c);
+///     *b += 10;
+///
+///     assert_eq!(9, *cells[2].borrow(&token));
+///     assert_eq!(15, *cells[5].borrow(&token));
+///     assert_eq!(2, *cells[9].borrow(&token));
+///
+///     let result = ghost_borrow_mut::borrow_many_mut(&cells, [2, 5, 2], &mut token);
+///     assert_eq!(Some(GhostIndexError::Aliasing), result.err());
+///
+///     let result = ghost_borrow_mut::borrow_many_mut(&cells, [2, 10], &mut token);
+///     assert_eq!(Some(GhostIndexError::OutOfBounds), result.err());
+/// });
+/// ```
+pub fn borrow_many_mut<'a, 'brand, T, const N: usize>(
+    cells: &'a [GhostCell<'brand, T>],
+    indices: [usize; N],
+    token: &'a mut GhostToken<'brand>,
+) -> Result<[&'a mut T; N], GhostIndexError> {
+    check_indices(cells.len(), indices)?;
+
+    //  Safety:
+    //  -   The indices were checked to be in bounds and distinct.
+    Ok(unsafe { borrow_many_mut_unchecked(cells, indices, token) })
+}
+
+/// Mutably borrows the `GhostCell`s of `cells` at `indices` at the same time, without checking the indices.
+///
+/// The references are returned in the order of `indices`.
+///
+/// #   Safety
+///
+/// The caller guarantees that all indices are in bounds, and distinct from one another.
+pub unsafe fn borrow_many_mut_unchecked<'a, 'brand, T, const N: usize>(
+    cells: &'a [GhostCell<'brand, T>],
+    indices: [usize; N],
+    _: &'a mut GhostToken<'brand>,
+) -> [&'a mut T; N] {
+    //  Safety:
+    //  -   Exclusive access to the `GhostToken` ensures exclusive access to the cells' content, if unaliased.
+    //  -   The caller guarantees the indices are in bounds and distinct, hence the cells are not aliased.
+    indices.map(|index| &mut *cells.get_unchecked(index).as_ptr())
+}
+
+//  Safe convenience methods for `Vec`, requiring multiple mutable borrows
+#[cfg(feature = "alloc")]
+#[forbid(unsafe_code)]
+impl<'brand, T> GhostCell<'brand, Vec<T>> {
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// Returns `GhostAliasingError`, leaving both untouched, if `self` and `other` are the same cell.
+    ///
+    /// See `Vec::append` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell, GhostAliasingError};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let a = GhostCell::new(vec![1, 2]);
+    ///     let b = GhostCell::new(vec![3, 4]);
+    ///
+    ///     a.append(&b, &mut token).unwrap();
+    ///
+    ///     assert_eq!(&[1, 2, 3, 4], &a.borrow(&token)[..]);
+    ///     assert!(b.borrow(&token).is_empty());
+    ///
+    ///     assert_eq!(Err(GhostAliasingError), a.append(&a, &mut token));
+    /// });
+    /// ```
+    pub fn append(&self, other: &Self, token: &mut GhostToken<'brand>) -> Result<(), GhostAliasingError> {
+        let (this, other) = (self, other).borrow_mut(token)?;
+
+        this.append(other);
+
+        Ok(())
+    }
+}
+
 //
 //  Implementation
 //
@@ -264,6 +386,22 @@ fn get_span<T: ?Sized>(value: &T) -> (*const u8, *const u8) {
     (start, end)
 }
 
+//  Returns `Ok(())` if all indices are within `0..len` and distinct, and `Err(GhostIndexError)` otherwise.
+fn check_indices<const N: usize>(len: usize, mut indices: [usize; N]) -> Result<(), GhostIndexError> {
+    indices.sort_unstable();
+
+    //  Once sorted, it suffices to check the last index for bounds, and adjacent indices for equality.
+    if matches!(indices.last(), Some(&last) if last >= len) {
+        return Err(GhostIndexError::OutOfBounds);
+    }
+
+    if indices.windows(2).any(|window| window[0] == window[1]) {
+        return Err(GhostIndexError::Aliasing);
+    }
+
+    Ok(())
+}
+
 //  Returns `Ok(())` if the inclusive ranges do not overlap, and `Err(GhostAliasingError)` otherwise.
 //
 //  Assumes that the ranges are _inclusive_.
@@ -600,4 +738,151 @@ fn check_distinct() {
     });
 }
 
+#[test]
+fn multiple_borrows_tuple_twelve() {
+    let value = GhostToken::new(|mut token| {
+        let cells: [GhostCell<i32>; 12] = core::array::from_fn(|i| GhostCell::new(i as i32));
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, cb] = &cells;
+
+        let (r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, ra, rb)
+            = (c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, cb).borrow_mut(&mut token).unwrap();
+
+        for (offset, reference) in [r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, ra, rb].into_iter().enumerate() {
+            *reference += 100 * offset as i32;
+        }
+
+        cells.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>()
+    });
+    assert_eq!((0..12).map(|i| 101 * i).collect::<Vec<_>>(), value);
+}
+
+#[test]
+fn multiple_borrows_tuple_twelve_unchecked() {
+    let value = GhostToken::new(|mut token| {
+        let cells: [GhostCell<i32>; 12] = core::array::from_fn(|i| GhostCell::new(i as i32));
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, cb] = &cells;
+
+        //  Safety:
+        //  -   The cells are all distinct.
+        let (r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, ra, rb)
+            = unsafe { (c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, cb).borrow_mut_unchecked(&mut token) };
+
+        for reference in [r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, ra, rb] {
+            *reference = -*reference;
+        }
+
+        cells.iter().map(|cell| *cell.borrow(&token)).sum::<i32>()
+    });
+    assert_eq!(-66, value);
+}
+
+#[test]
+fn multiple_borrows_tuple_twelve_aliased() {
+    GhostToken::new(|mut token| {
+        let cells: [GhostCell<i32>; 12] = core::array::from_fn(|i| GhostCell::new(i as i32));
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, _] = &cells;
+
+        let result = (c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, ca, c4).borrow_mut(&mut token);
+
+        assert_eq!(Some(GhostAliasingError), result.err());
+    });
+}
+
+#[test]
+fn borrow_many_mut_by_index() {
+    let value = GhostToken::new(|mut token| {
+        let cells: Vec<_> = (0..10).map(GhostCell::new).collect();
+
+        let [a, b, c] = borrow_many_mut(&cells, [9, 2, 5], &mut token).unwrap();
+        *a += 100;
+        *b += 200;
+        *c += 300;
+
+        cells.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>()
+    });
+    assert_eq!(vec![0, 1, 202, 3, 4, 305, 6, 7, 8, 109], value);
+}
+
+#[test]
+fn borrow_many_mut_preserves_order() {
+    let value = GhostToken::new(|mut token| {
+        let cells: Vec<_> = (0..6).map(|i| GhostCell::new(i * 10)).collect();
+
+        let [three, zero, five] = borrow_many_mut(&cells, [3, 0, 5], &mut token).unwrap();
+        assert_eq!((30, 0, 50), (*three, *zero, *five));
+
+        *three += 3;
+        *zero += 1;
+        *five += 5;
+
+        cells.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>()
+    });
+    assert_eq!(vec![1, 10, 20, 33, 40, 55], value);
+}
+
+#[test]
+fn borrow_many_mut_empty() {
+    GhostToken::new(|mut token| {
+        let cells: Vec<GhostCell<i32>> = Vec::new();
+
+        let borrowed: [&mut i32; 0] = borrow_many_mut(&cells, [], &mut token).unwrap();
+        assert!(borrowed.is_empty());
+    });
+}
+
+#[test]
+fn borrow_many_mut_invalid() {
+    GhostToken::new(|mut token| {
+        let cells: Vec<_> = (0..4).map(GhostCell::new).collect();
+
+        assert_eq!(Some(GhostIndexError::Aliasing), borrow_many_mut(&cells, [1, 3, 1], &mut token).err());
+        assert_eq!(Some(GhostIndexError::Aliasing), borrow_many_mut(&cells, [0, 0], &mut token).err());
+        assert_eq!(Some(GhostIndexError::OutOfBounds), borrow_many_mut(&cells, [4], &mut token).err());
+        assert_eq!(Some(GhostIndexError::OutOfBounds), borrow_many_mut(&cells, [0, usize::MAX], &mut token).err());
+    });
+}
+
+#[test]
+fn borrow_many_mut_unchecked_by_index() {
+    let value = GhostToken::new(|mut token| {
+        let cells: Vec<_> = (0..4).map(GhostCell::new).collect();
+
+        //  Safety:
+        //  -   The indices are in bounds and distinct.
+        let [a, b] = unsafe { borrow_many_mut_unchecked(&cells, [3, 0], &mut token) };
+        mem::swap(a, b);
+
+        cells.iter().map(|cell| *cell.borrow(&token)).collect::<Vec<_>>()
+    });
+    assert_eq!(vec![3, 1, 2, 0], value);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn append_distinct_cells() {
+    GhostToken::new(|mut token| {
+        let a = GhostCell::new(vec![String::from("a")]);
+        let b = GhostCell::new(vec![String::from("b"), String::from("c")]);
+
+        assert_eq!(Ok(()), a.append(&b, &mut token));
+        assert_eq!(&["a", "b", "c"], &a.borrow(&token)[..]);
+        assert!(b.borrow(&token).is_empty());
+
+        //  Appending an empty vector is a no-op.
+        assert_eq!(Ok(()), a.append(&b, &mut token));
+        assert_eq!(3, a.borrow(&token).len());
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn append_self_is_error() {
+    GhostToken::new(|mut token| {
+        let a = GhostCell::new(vec![1, 2, 3]);
+
+        assert_eq!(Err(GhostAliasingError), a.append(&a, &mut token));
+        assert_eq!(&[1, 2, 3], &a.borrow(&token)[..]);
+    });
+}
+
 } // mod tests
\ No newline at end of file
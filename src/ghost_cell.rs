@@ -4,10 +4,16 @@
 
 use core::{
     cell::UnsafeCell,
+    cmp::Ordering,
+    fmt,
     marker::PhantomData,
     mem,
+    ops::Neg,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// A `GhostToken<'x>` is _the_ key to access the content of any `&GhostCell<'x, _>` sharing the same brand.
 ///
 /// Each `GhostToken<'x>` is created alongside a unique brand (its lifetime), and each `GhostCell<'x, T>` is associated
@@ -16,6 +22,12 @@ use core::{
 /// to.
 ///
 /// The pool of `GhostCell` associated to a token need not be homogeneous, each may own a value of a different type.
+///
+/// #   Async
+///
+/// A `GhostToken`, owned or borrowed, may be held across `.await` points. Being both `Send` and `Sync`, the token
+/// itself never prevents the resulting future from being `Send`; the future is `Send` as long as the `GhostCell`s it
+/// borrows are `Sync`, that is as long as their contents are `Send` and `Sync`.
 pub struct GhostToken<'brand> { _marker: InvariantLifetime<'brand> }
 
 impl<'brand> GhostToken<'brand> {
@@ -108,6 +120,79 @@ impl<'brand, T> GhostCell<'brand, T> {
     /// assert_eq!(42, value);
     /// ```
     pub fn into_inner(self) -> T { self.value.into_inner() }
+
+    /// Turns a mutably borrowed slice into a mutably borrowed slice of `GhostCell`s.
+    ///
+    /// `t` is mutably borrowed for the lifetime of the result, ensuring the absence of aliasing.
+    ///
+    /// This is the slice counterpart of `from_mut`; see also `as_slice_of_cells`, which turns a cell containing a slice
+    /// into a slice of cells.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// let mut array = [0; 8];
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cells: &[GhostCell<_>] = GhostCell::from_mut_slice(&mut array);
+    ///
+    ///     let (first, last): (&GhostCell<_>, &GhostCell<_>) = (&cells[0], &cells[7]);
+    ///     let aliases: Vec<_> = vec![first, last, first];
+    ///
+    ///     *aliases[0].borrow_mut(&mut token) += 1;
+    ///     *aliases[1].borrow_mut(&mut token) = 7;
+    ///     *aliases[2].borrow_mut(&mut token) += 1;
+    /// });
+    ///
+    /// assert_eq!([2, 0, 0, 0, 0, 0, 0, 7], array);
+    /// ```
+    pub fn from_mut_slice(t: &mut [T]) -> &mut [Self] {
+        //  Safety:
+        //  -   `t` is mutably borrowed for the duration.
+        //  -   `GhostCell<'_, T>` has the same in-memory representation as `T`.
+        unsafe { &mut *(t as *mut [T] as *mut [Self]) }
+    }
+
+    /// Turns a mutably borrowed slice of `GhostCell`s into a mutably borrowed slice of their contents.
+    ///
+    /// `cells` is mutably borrowed for the lifetime of the result, hence no token is required. This is the inverse of
+    /// `from_mut_slice`, and the slice counterpart of `get_mut`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// struct Soa<'brand> {
+    ///     xs: Box<[GhostCell<'brand, f32>]>,
+    ///     ys: Box<[GhostCell<'brand, f32>]>,
+    /// }
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut soa = Soa {
+    ///         xs: (0..3).map(|i| GhostCell::new(i as f32)).collect(),
+    ///         ys: (0..3).map(|_| GhostCell::new(1.0)).collect(),
+    ///     };
+    ///
+    ///     let (xs, ys) = (GhostCell::get_mut_slice(&mut soa.xs), GhostCell::get_mut_slice(&mut soa.ys));
+    ///
+    ///     for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+    ///         *y += *x;
+    ///         *x *= 2.0;
+    ///     }
+    ///
+    ///     assert_eq!(4.0, *soa.xs[2].borrow(&token));
+    ///     assert_eq!(3.0, *soa.ys[2].borrow(&token));
+    /// });
+    /// ```
+    pub fn get_mut_slice(cells: &mut [Self]) -> &mut [T] {
+        //  Safety:
+        //  -   `cells` is mutably borrowed for the duration, ensuring exclusive access to the cells' content.
+        //  -   `GhostCell` is `repr(transparent)`, hence `T` and `GhostCell<T>` have the same memory representation.
+        unsafe { &mut *(cells as *mut [Self] as *mut [T]) }
+    }
 }
 
 impl<'brand, T: ?Sized> GhostCell<'brand, T> {
@@ -292,6 +377,184 @@ impl<'brand, T> GhostCell<'brand, T> {
         self.replace(T::default(), token)
     }
 
+    /// Swaps the value of the cell with `local`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(42);
+    ///     let mut local = 33;
+    ///
+    ///     cell.swap_value(&mut local, &mut token);
+    ///
+    ///     assert_eq!(33, *cell.borrow(&token));
+    ///     assert_eq!(42, local);
+    /// });
+    /// ```
+    pub fn swap_value(&self, local: &mut T, token: &mut GhostToken<'brand>) {
+        mem::swap(self.borrow_mut(token), local);
+    }
+
+    /// Swaps two disjoint parts of the value of the cell, as selected by `fields`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// struct Buffers { front: Vec<u8>, back: Vec<u8> }
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(Buffers { front: vec![1, 2], back: vec![3] });
+    ///
+    ///     cell.swap_fields(&mut token, |buffers| (&mut buffers.front, &mut buffers.back));
+    ///
+    ///     let buffers = cell.borrow(&token);
+    ///     assert_eq!((&[3][..], &[1, 2][..]), (&buffers.front[..], &buffers.back[..]));
+    /// });
+    /// ```
+    pub fn swap_fields<U, F>(&self, token: &mut GhostToken<'brand>, fields: F)
+    where
+        F: FnOnce(&mut T) -> (&mut U, &mut U),
+    {
+        let (a, b) = fields(self.borrow_mut(token));
+
+        mem::swap(a, b);
+    }
+
+    /// Fetches the value, and applies `f` to it: if `f` returns `Some`, stores the new value and returns `Ok` with
+    /// the previous value, otherwise returns `Err` with the current, unchanged, value.
+    ///
+    /// This mirrors `AtomicUsize::fetch_update`, without the need for a loop since no concurrent update may occur.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(7);
+    ///
+    ///     assert_eq!(Ok(7), cell.fetch_update(&mut token, |x| Some(x + 1)));
+    ///     assert_eq!(8, *cell.borrow(&token));
+    ///
+    ///     assert_eq!(Err(8), cell.fetch_update(&mut token, |x| if x < 8 { Some(x + 1) } else { None }));
+    ///     assert_eq!(8, *cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn fetch_update<F>(&self, token: &mut GhostToken<'brand>, mut f: F) -> Result<T, T>
+    where
+        T: Copy,
+        F: FnMut(T) -> Option<T>,
+    {
+        let value = self.borrow_mut(token);
+        let previous = *value;
+
+        match f(previous) {
+            Some(next) => {
+                *value = next;
+                Ok(previous)
+            }
+            None => Err(previous),
+        }
+    }
+
+    /// Computes a new value from a shared view of the current value: if `f` returns `Ok`, stores the new value and
+    /// returns `Ok` with the previous value, otherwise returns the error and leaves the cell untouched.
+    ///
+    /// Since `f` only sees a shared reference, no partial mutation may occur on error.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum State { Idle, Running(u32), Done }
+    ///
+    /// fn start(state: &State) -> Result<State, &'static str> {
+    ///     match state {
+    ///         State::Idle => Ok(State::Running(0)),
+    ///         _ => Err("already started"),
+    ///     }
+    /// }
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(State::Idle);
+    ///
+    ///     assert_eq!(Ok(State::Idle), cell.try_transition(&mut token, start));
+    ///     assert_eq!(State::Running(0), *cell.borrow(&token));
+    ///
+    ///     assert_eq!(Err("already started"), cell.try_transition(&mut token, start));
+    ///     assert_eq!(State::Running(0), *cell.borrow(&token));
+    ///
+    ///     assert_eq!(Ok(State::Running(0)), cell.try_transition::<(), _>(&mut token, |_| Ok(State::Done)));
+    ///     assert_eq!(State::Done, *cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn try_transition<E, F>(&self, token: &mut GhostToken<'brand>, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&T) -> Result<T, E>,
+    {
+        let next = f(self.borrow(token))?;
+
+        Ok(self.replace(next, token))
+    }
+
+    /// Negates the value of the cell in place, and returns the new value.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(42);
+    ///
+    ///     assert_eq!(-42, cell.negate(&mut token));
+    ///     assert_eq!(-42, *cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn negate(&self, token: &mut GhostToken<'brand>) -> T
+    where
+        T: Neg<Output = T> + Copy,
+    {
+        let value = self.borrow_mut(token);
+        *value = -*value;
+        *value
+    }
+
+    /// Extends the collection contained in the cell with the content of an iterator.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let vec = GhostCell::new(vec![1, 2]);
+    ///     let set = GhostCell::new(HashSet::new());
+    ///
+    ///     vec.extend([3, 4], &mut token);
+    ///     set.extend([3, 1, 3], &mut token);
+    ///
+    ///     assert_eq!(&[1, 2, 3, 4], &vec.borrow(&token)[..]);
+    ///     assert_eq!(&HashSet::from([1, 3]), set.borrow(&token));
+    /// });
+    /// ```
+    pub fn extend<I>(&self, iter: I, token: &mut GhostToken<'brand>)
+    where
+        I: IntoIterator,
+        T: Extend<I::Item>,
+    {
+        self.borrow_mut(token).extend(iter);
+    }
+
     /// Swaps the values of two cells.
     ///
     /// If the cells fully overlap, i.e. they have the same address, they are "swapped" (a no-op) and `Ok` is returned.
@@ -328,74 +591,865 @@ impl<'brand, T> GhostCell<'brand, T> {
     }
 }
 
-impl<'brand, T: Default> Default for GhostCell<'brand, T> {
-    fn default() -> Self {
-        Self::new(T::default())
+//  Safe convenience methods for `heapless::Vec`
+#[cfg(feature = "heapless")]
+#[forbid(unsafe_code)]
+impl<'brand, T, const N: usize> GhostCell<'brand, heapless::Vec<T, N>> {
+    /// Appends `value` to the back of the vector, or returns it if the vector is full.
+    ///
+    /// See `heapless::Vec::push` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(heapless::Vec::<i32, 2>::new());
+    ///
+    ///     assert_eq!(Ok(()), cell.push(1, &mut token));
+    ///     assert_eq!(Ok(()), cell.push(2, &mut token));
+    ///     assert_eq!(Err(3), cell.push(3, &mut token));
+    ///
+    ///     assert_eq!(&[1, 2], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn push(&self, value: T, token: &mut GhostToken<'brand>) -> Result<(), T> {
+        self.borrow_mut(token).push(value)
     }
-}
 
-impl<'brand, T> GhostCell<'brand, [T]> {
-    /// Returns a slice of cells from a cell containing a slice.
+    /// Clears the vector, removing all values.
     ///
     /// #   Example
     ///
     /// ```rust
     /// use ghost_cell::{GhostToken, GhostCell};
     ///
-    /// let n = 12;
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(heapless::Vec::<i32, 4>::from_slice(&[1, 2, 3]).unwrap());
     ///
-    /// let value = GhostToken::new(|mut token| {
-    ///     let mut vec: Vec<_> = (0..n).collect();
-    ///     let cell = GhostCell::from_mut(&mut vec[..]);
+    ///     cell.clear(&mut token);
     ///
-    ///     let slice = cell.as_slice_of_cells();
+    ///     assert!(cell.borrow(&token).is_empty());
+    /// });
+    /// ```
+    pub fn clear(&self, token: &mut GhostToken<'brand>) {
+        self.borrow_mut(token).clear();
+    }
+
+    /// Returns an iterator over the values of the vector.
     ///
-    ///     *slice[n / 2].borrow_mut(&mut token) = 33;
+    /// #   Example
     ///
-    ///     vec[n / 2]
-    /// });
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
     ///
-    /// assert_eq!(33, value);
+    /// GhostToken::new(|token| {
+    ///     let cell = GhostCell::new(heapless::Vec::<i32, 4>::from_slice(&[1, 2, 3]).unwrap());
+    ///
+    ///     assert_eq!(6, cell.iter(&token).sum::<i32>());
+    /// });
     /// ```
-    pub fn as_slice_of_cells(&self) -> &[GhostCell<'brand, T>] {
-        //  Safety:
-        //  -   Same lifetime.
-        //  -   `GhostCell<'_, T>` has the same in-memory representation as `T`.
-        unsafe { &*(self.as_ptr() as *mut [GhostCell<'brand, T>]) }
+    pub fn iter<'a>(&'a self, token: &'a GhostToken<'brand>) -> core::slice::Iter<'a, T> {
+        self.borrow(token).iter()
     }
 }
 
-impl<'brand, T: ?Sized> AsMut<T> for GhostCell<'brand, T> {
-    fn as_mut(&mut self) -> &mut T { self.get_mut() }
+//  Safe convenience methods for `bool`
+#[forbid(unsafe_code)]
+impl<'brand> GhostCell<'brand, bool> {
+    /// Toggles the value of the cell, and returns the new value.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(false);
+    ///
+    ///     assert!(cell.toggle(&mut token));
+    ///     assert!(!cell.toggle(&mut token));
+    ///     assert!(!*cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn toggle(&self, token: &mut GhostToken<'brand>) -> bool {
+        let value = self.borrow_mut(token);
+        *value = !*value;
+        *value
+    }
 }
 
-impl<'brand, T> From<T> for GhostCell<'brand, T> {
-    fn from(t: T) -> Self { Self::new(t) }
-}
+//  Safe convenience methods for exclusively borrowed slices of cells
+#[forbid(unsafe_code)]
+impl<'brand, T> GhostCell<'brand, T> {
+    /// Orders the values of the cells at indices `i` and `j`, swapping them if necessary, such that the value at `i`
+    /// is less than or equal to the value at `j` afterwards.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `i` or `j` are out of bounds.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells = [GhostCell::new(3), GhostCell::new(1), GhostCell::new(4), GhostCell::new(2)];
+    ///
+    ///     //  A sorting network for 4 elements.
+    ///     for (i, j) in [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)] {
+    ///         GhostCell::compare_and_swap_indices(&mut cells, i, j);
+    ///     }
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![1, 2, 3, 4], values);
+    /// });
+    /// ```
+    pub fn compare_and_swap_indices(cells: &mut [Self], i: usize, j: usize)
+    where
+        T: Ord,
+    {
+        let (low, high) = match i.cmp(&j) {
+            Ordering::Less => (i, j),
+            Ordering::Greater => (j, i),
+            Ordering::Equal => {
+                assert!(i < cells.len(), "index {} out of bounds for length {}", i, cells.len());
+                return;
+            }
+        };
 
-/// A `GhostCell<'_, T>` owns a `T`, so it cannot be sent across threads if `T` cannot.
-///
-/// Conversely, a `GhostCell` does not add any state on top of `T`, so if `T` can be sent across threads, so can
-/// `GhostCell<'_, T>`
-unsafe impl<'brand, T: ?Sized + Send> Send for GhostCell<'brand, T> {}
+        let (head, tail) = cells.split_at_mut(high);
+        let (low, high) = (head[low].get_mut(), tail[0].get_mut());
 
-/// A `GhostCell<'_, T>` owns a `T`, so it cannot be accessed from different threads if `T` cannot.
-///
-/// Conversely, a `GhostCell` does not add any state on top of `T`, so if `T` can be accessed from different threads,
-/// so can `GhostCell<'_, T>`. `T` also needs to be sendable across threads,
-/// because a `T` can be extracted from a `&GhostCell<'brand, T>` via [`GhostCell::replace`].
-unsafe impl<'brand, T: ?Sized + Send + Sync> Sync for GhostCell<'brand, T> {}
+        //  `i` and `j` may be in either order, the value at `i` must end up being the lesser one.
+        let misordered = if i < j { low > high } else { high > low };
 
-//
-//  Implementation
-//
+        if misordered {
+            mem::swap(low, high);
+        }
+    }
 
-type InvariantLifetime<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+    /// Visits the values of the cells in order, carrying `state` from one to the next, and returns the final state.
+    ///
+    /// Only one value is mutably borrowed at a time, any value needed from previous cells must be carried in `state`.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells = [GhostCell::new(1), GhostCell::new(2), GhostCell::new(3), GhostCell::new(4)];
+    ///
+    ///     //  In-place prefix sum.
+    ///     let total = GhostCell::scan_mut(&mut cells, 0, |sum, x| {
+    ///         *sum += *x;
+    ///         *x = *sum;
+    ///     });
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![1, 3, 6, 10], values);
+    ///     assert_eq!(10, total);
+    /// });
+    /// ```
+    pub fn scan_mut<S, F>(cells: &mut [Self], init: S, mut f: F) -> S
+    where
+        F: FnMut(&mut S, &mut T),
+    {
+        let mut state = init;
 
-#[doc(hidden)]
-pub mod compile_tests {
+        for cell in cells {
+            f(&mut state, cell.get_mut());
+        }
 
-/// ```compile_fail
+        state
+    }
+
+    /// Rotates the cells in-place such that the first `mid` cells move to the end.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required. See `slice::rotate_left` for details.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `mid > cells.len()`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells: Vec<_> = (0..5).map(GhostCell::new).collect();
+    ///
+    ///     GhostCell::rotate_left_cells(&mut cells, 2);
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![2, 3, 4, 0, 1], values);
+    /// });
+    /// ```
+    pub fn rotate_left_cells(cells: &mut [Self], mid: usize) {
+        cells.rotate_left(mid);
+    }
+
+    /// Rotates the cells in-place such that the last `k` cells move to the front.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required. See `slice::rotate_right` for details.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `k > cells.len()`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells: Vec<_> = (0..5).map(GhostCell::new).collect();
+    ///
+    ///     GhostCell::rotate_right_cells(&mut cells, 2);
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![3, 4, 0, 1, 2], values);
+    /// });
+    /// ```
+    pub fn rotate_right_cells(cells: &mut [Self], k: usize) {
+        cells.rotate_right(k);
+    }
+
+    /// Swaps the cells of `a` with the cells of `b`, element-wise.
+    ///
+    /// `a` and `b` are mutably borrowed for the duration, hence no token is required. See `slice::swap_with_slice`
+    /// for details.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut front: Vec<_> = (0..4).map(GhostCell::new).collect();
+    ///     let mut back: Vec<_> = (10..14).map(GhostCell::new).collect();
+    ///
+    ///     GhostCell::swap_slices(&mut front, &mut back);
+    ///
+    ///     let front: Vec<_> = front.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     let back: Vec<_> = back.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///
+    ///     assert_eq!(vec![10, 11, 12, 13], front);
+    ///     assert_eq!(vec![0, 1, 2, 3], back);
+    /// });
+    /// ```
+    pub fn swap_slices(a: &mut [Self], b: &mut [Self]) {
+        a.swap_with_slice(b);
+    }
+
+    /// Reverses the order of the cells, in place.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required. See `reverse_contents` to reverse the
+    /// values of a shared slice of cells with the token.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells: Vec<_> = (0..4).map(GhostCell::new).collect();
+    ///
+    ///     GhostCell::reverse_cells(&mut cells);
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![3, 2, 1, 0], values);
+    /// });
+    /// ```
+    pub fn reverse_cells(cells: &mut [Self]) {
+        cells.reverse();
+    }
+
+    /// Reverses the order of the values of the cells, in place, leaving the cells themselves in place.
+    ///
+    /// Only a shared borrow of `cells` is required, the values being swapped pairwise with the token. See
+    /// `reverse_cells` to reverse an exclusively borrowed slice of cells without a token.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cells: Vec<_> = (0..5).map(GhostCell::new).collect();
+    ///     let middle = &cells[2];
+    ///
+    ///     GhostCell::reverse_contents(&cells, &mut token);
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![4, 3, 2, 1, 0], values);
+    ///     assert_eq!(2, *middle.borrow(&token));
+    /// });
+    /// ```
+    #[cfg(feature = "experimental-multiple-mutable-borrows")]
+    pub fn reverse_contents(cells: &[Self], token: &mut GhostToken<'brand>) {
+        let (front, back) = cells.split_at(cells.len() / 2);
+
+        for (low, high) in front.iter().zip(back.iter().rev()) {
+            low.swap(high, token).expect("distinct cells of a slice do not overlap");
+        }
+    }
+
+    /// Reorders the cells in-place such that those whose value matches `predicate` come first, and returns the number
+    /// of matching cells, that is the index of the first non-matching cell, if any.
+    ///
+    /// The cells matching `predicate` keep their relative order, the others may not.
+    ///
+    /// `cells` is mutably borrowed for the duration, hence no token is required.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut cells: Vec<_> = [5, 2, 7, 8, 1, 4].into_iter().map(GhostCell::new).collect();
+    ///
+    ///     let pivot = GhostCell::partition_cells(&mut cells, |x| x % 2 == 0);
+    ///     assert_eq!(3, pivot);
+    ///
+    ///     let values: Vec<_> = cells.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(&[2, 8, 4], &values[..pivot]);
+    ///     assert!(values[pivot..].iter().all(|x| x % 2 == 1));
+    /// });
+    /// ```
+    pub fn partition_cells<F>(cells: &mut [Self], mut predicate: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut pivot = 0;
+
+        for index in 0..cells.len() {
+            if predicate(cells[index].get_mut()) {
+                cells.swap(pivot, index);
+                pivot += 1;
+            }
+        }
+
+        pivot
+    }
+
+    /// Applies `f` to each pair of values of `a` and `b`, at the same index, mutating the values of `a`.
+    ///
+    /// `a` is mutably borrowed for the duration, hence the token is only required to read the values of `b`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `a` and `b` have different lengths.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let mut a: Vec<_> = [1.0, 2.0, 3.0, 4.0].into_iter().map(GhostCell::new).collect();
+    ///     let b: Vec<_> = [0.5, 0.5, 1.0, 2.0].into_iter().map(GhostCell::new).collect();
+    ///
+    ///     GhostCell::zip_mut_with(&mut a, &b, &token, |x, y| *x += *y);
+    ///
+    ///     let a: Vec<_> = a.iter().map(|cell| *cell.borrow(&token)).collect();
+    ///     assert_eq!(vec![1.5, 2.5, 4.0, 6.0], a);
+    /// });
+    /// ```
+    pub fn zip_mut_with<U, F>(a: &mut [Self], b: &[GhostCell<'brand, U>], token: &GhostToken<'brand>, mut f: F)
+    where
+        F: FnMut(&mut T, &U),
+    {
+        assert_eq!(a.len(), b.len(), "slices of cells must have the same length");
+
+        for (x, y) in a.iter_mut().zip(b) {
+            f(x.get_mut(), y.borrow(token));
+        }
+    }
+
+    /// Stores the result of `f` applied to each value of `src` into the cell of `dst` at the same index.
+    ///
+    /// `dst` is mutably borrowed for the duration, hence the token is only required to read the values of `src`.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let src: Vec<GhostCell<i32>> = [-1, 0, 42].into_iter().map(GhostCell::new).collect();
+    ///     let mut dst: Vec<GhostCell<String>> = (0..3).map(|_| GhostCell::default()).collect();
+    ///
+    ///     GhostCell::map_into_arena(&src, &mut dst, &token, |x| format!("#{}", x));
+    ///
+    ///     let dst: Vec<_> = dst.iter().map(|cell| cell.borrow(&token).as_str()).collect();
+    ///     assert_eq!(vec!["#-1", "#0", "#42"], dst);
+    /// });
+    /// ```
+    pub fn map_into_arena<U, F>(src: &[GhostCell<'brand, U>], dst: &mut [Self], token: &GhostToken<'brand>, mut f: F)
+    where
+        F: FnMut(&U) -> T,
+    {
+        assert_eq!(src.len(), dst.len(), "slices of cells must have the same length");
+
+        for (x, y) in src.iter().zip(dst) {
+            *y.get_mut() = f(x.borrow(token));
+        }
+    }
+}
+
+impl<'brand, T: Default> Default for GhostCell<'brand, T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<'brand, T> GhostCell<'brand, [T]> {
+    /// Returns a slice of cells from a cell containing a slice.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// let n = 12;
+    ///
+    /// let value = GhostToken::new(|mut token| {
+    ///     let mut vec: Vec<_> = (0..n).collect();
+    ///     let cell = GhostCell::from_mut(&mut vec[..]);
+    ///
+    ///     let slice = cell.as_slice_of_cells();
+    ///
+    ///     *slice[n / 2].borrow_mut(&mut token) = 33;
+    ///
+    ///     vec[n / 2]
+    /// });
+    ///
+    /// assert_eq!(33, value);
+    /// ```
+    pub fn as_slice_of_cells(&self) -> &[GhostCell<'brand, T>] {
+        //  Safety:
+        //  -   Same lifetime.
+        //  -   `GhostCell<'_, T>` has the same in-memory representation as `T`.
+        unsafe { &*(self.as_ptr() as *mut [GhostCell<'brand, T>]) }
+    }
+}
+
+//  Safe convenience methods for slices
+#[cfg(feature = "alloc")]
+#[forbid(unsafe_code)]
+impl<'brand, T> GhostCell<'brand, [T]> {
+    /// Sorts the slice.
+    ///
+    /// See `slice::sort` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let mut boxed: Box<[i32]> = Box::new([3, 1, 4, 1, 5]);
+    ///     let cell: &GhostCell<[i32]> = GhostCell::from_mut(&mut *boxed);
+    ///
+    ///     cell.sort(&mut token);
+    ///
+    ///     assert_eq!(&[1, 1, 3, 4, 5], cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn sort(&self, token: &mut GhostToken<'brand>)
+    where
+        T: Ord,
+    {
+        self.borrow_mut(token).sort();
+    }
+
+    /// Sorts the slice with a comparator function.
+    ///
+    /// See `slice::sort_by` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let mut boxed: Box<[i32]> = Box::new([3, 1, 4, 1, 5]);
+    ///     let cell: &GhostCell<[i32]> = GhostCell::from_mut(&mut *boxed);
+    ///
+    ///     cell.sort_by(&mut token, |a, b| b.cmp(a));
+    ///
+    ///     assert_eq!(&[5, 4, 3, 1, 1], cell.borrow(&token));
+    /// });
+    /// ```
+    pub fn sort_by<F>(&self, token: &mut GhostToken<'brand>, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.borrow_mut(token).sort_by(compare);
+    }
+}
+
+//  Safe convenience methods for `Vec`
+#[cfg(feature = "alloc")]
+#[forbid(unsafe_code)]
+impl<'brand, T> GhostCell<'brand, Vec<T>> {
+    /// Resizes the vector in-place so that its length is equal to `new_len`.
+    ///
+    /// See `Vec::resize` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3]);
+    ///
+    ///     cell.resize(5, 0, &mut token);
+    ///     assert_eq!(&[1, 2, 3, 0, 0], &cell.borrow(&token)[..]);
+    ///
+    ///     cell.resize(2, 0, &mut token);
+    ///     assert_eq!(&[1, 2], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn resize(&self, new_len: usize, value: T, token: &mut GhostToken<'brand>)
+    where
+        T: Clone,
+    {
+        self.borrow_mut(token).resize(new_len, value);
+    }
+
+    /// Fills the vector with clones of `value`.
+    ///
+    /// See `slice::fill` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3]);
+    ///
+    ///     cell.fill(7, &mut token);
+    ///
+    ///     assert_eq!(&[7, 7, 7], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn fill(&self, value: T, token: &mut GhostToken<'brand>)
+    where
+        T: Clone,
+    {
+        self.borrow_mut(token).fill(value);
+    }
+
+    /// Retains only the elements for which `f` returns `true`, passing a mutable reference to each element.
+    ///
+    /// See `Vec::retain_mut` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3, 4, 5]);
+    ///
+    ///     cell.retain_mut(&mut token, |x| {
+    ///         *x *= 10;
+    ///         *x != 20 && *x != 40
+    ///     });
+    ///
+    ///     assert_eq!(&[10, 30, 50], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn retain_mut<F>(&self, token: &mut GhostToken<'brand>, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.borrow_mut(token).retain_mut(f);
+    }
+
+    /// Splits the vector in two at the given index, returning the tail `[at, len)` and leaving the head `[0, at)` in
+    /// the cell.
+    ///
+    /// See `Vec::split_off` for details.
+    ///
+    /// #   Panics
+    ///
+    /// Panics if `at > len`.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3, 4, 5]);
+    ///
+    ///     let tail = cell.split_off(2, &mut token);
+    ///
+    ///     assert_eq!(&[1, 2], &cell.borrow(&token)[..]);
+    ///     assert_eq!(vec![3, 4, 5], tail);
+    /// });
+    /// ```
+    pub fn split_off(&self, at: usize, token: &mut GhostToken<'brand>) -> Vec<T> {
+        self.borrow_mut(token).split_off(at)
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that resolve to the same key.
+    ///
+    /// See `Vec::dedup_by_key` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![10, 11, 20, 21, 22, 30, 15]);
+    ///
+    ///     cell.dedup_by_key(&mut token, |x| *x / 10);
+    ///
+    ///     assert_eq!(&[10, 20, 30, 15], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn dedup_by_key<K, F>(&self, token: &mut GhostToken<'brand>, key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&mut T) -> K,
+    {
+        self.borrow_mut(token).dedup_by_key(key);
+    }
+
+    /// Removes the last element from the vector and returns it, or `None` if it is empty.
+    ///
+    /// See `Vec::pop` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3]);
+    ///
+    ///     let mut popped = Vec::new();
+    ///
+    ///     while let Some(x) = cell.pop(&mut token) {
+    ///         popped.push(x);
+    ///     }
+    ///
+    ///     assert_eq!(vec![3, 2, 1], popped);
+    ///     assert!(cell.borrow(&token).is_empty());
+    ///     assert_eq!(None, cell.pop(&mut token));
+    /// });
+    /// ```
+    pub fn pop(&self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.borrow_mut(token).pop()
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// See `Vec::truncate` for details.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let cell = GhostCell::new(vec![1, 2, 3, 4, 5]);
+    ///
+    ///     cell.truncate(2, &mut token);
+    ///     assert_eq!(&[1, 2], &cell.borrow(&token)[..]);
+    ///
+    ///     //  No effect if `len` is greater than the current length.
+    ///     cell.truncate(8, &mut token);
+    ///     assert_eq!(&[1, 2], &cell.borrow(&token)[..]);
+    /// });
+    /// ```
+    pub fn truncate(&self, len: usize, token: &mut GhostToken<'brand>) {
+        self.borrow_mut(token).truncate(len);
+    }
+}
+
+impl<'brand, T: ?Sized> AsMut<T> for GhostCell<'brand, T> {
+    fn as_mut(&mut self) -> &mut T { self.get_mut() }
+}
+
+impl<'brand, T> From<T> for GhostCell<'brand, T> {
+    fn from(t: T) -> Self { Self::new(t) }
+}
+
+/// A `GhostCell<'_, T>` owns a `T`, so it cannot be sent across threads if `T` cannot.
+///
+/// Conversely, a `GhostCell` does not add any state on top of `T`, so if `T` can be sent across threads, so can
+/// `GhostCell<'_, T>`
+unsafe impl<'brand, T: ?Sized + Send> Send for GhostCell<'brand, T> {}
+
+/// A `GhostCell<'_, T>` owns a `T`, so it cannot be accessed from different threads if `T` cannot.
+///
+/// Conversely, a `GhostCell` does not add any state on top of `T`, so if `T` can be accessed from different threads,
+/// so can `GhostCell<'_, T>`. `T` also needs to be sendable across threads,
+/// because a `T` can be extracted from a `&GhostCell<'brand, T>` via [`GhostCell::replace`].
+unsafe impl<'brand, T: ?Sized + Send + Sync> Sync for GhostCell<'brand, T> {}
+
+/// Without a token, the content of the cell cannot be accessed, hence only a placeholder is formatted.
+///
+/// Use [`GhostCell::borrow`] to format the content of the cell.
+impl<'brand, T: ?Sized> fmt::Debug for GhostCell<'brand, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GhostCell").finish_non_exhaustive()
+    }
+}
+
+/// Deserializes a `T`, and wraps it into a new `GhostCell`.
+///
+/// #   Serialization
+///
+/// `GhostCell` does not implement `Serialize`: reading its content through `&self` alone would be unsound, as a
+/// mutable reference to the content may be alive at the same time. `borrow_mut` only borrows the cell immutably, and
+/// it is the token, not the cell, which it borrows mutably.
+///
+/// Instead, serialize the content borrowed with the token, for example via `GhostCell::borrow` or `GhostBorrow`.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostBorrow};
+///
+/// GhostToken::new(|token| {
+///     let cells: Vec<GhostCell<i32>> = serde_json::from_str("[1, 2, 3]").unwrap();
+///
+///     let json = serde_json::to_string(cells.borrow(&token)).unwrap();
+///
+///     assert_eq!("[1,2,3]", json);
+/// });
+/// ```
+#[cfg(feature = "serde")]
+impl<'de, 'brand, T: serde::Deserialize<'de>> serde::Deserialize<'de> for GhostCell<'brand, T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'brand, T> GhostCell<'brand, T> {
+    /// Returns a `serde::de::DeserializeSeed`, which deserializes a `T` and stores it into this very cell.
+    ///
+    /// This allows deserializing into the cells of a pre-existing arena, rather than into new cells.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let arena: Vec<_> = (0..3).map(GhostCell::new).collect();
+    ///
+    ///     let mut deserializer = serde_json::Deserializer::from_str("42");
+    ///     arena[1].deserialize_seed(&mut token).deserialize(&mut deserializer).unwrap();
+    ///
+    ///     assert_eq!(42, *arena[1].borrow(&token));
+    /// });
+    /// ```
+    pub fn deserialize_seed<'a>(&'a self, token: &'a mut GhostToken<'brand>) -> GhostCellSeed<'a, 'brand, T> {
+        GhostCellSeed { cell: self, token }
+    }
+}
+
+/// Deserializes the content of a `GhostCell` in place, see [`GhostCell::deserialize_seed`].
+#[cfg(feature = "serde")]
+pub struct GhostCellSeed<'a, 'brand, T> {
+    cell: &'a GhostCell<'brand, T>,
+    token: &'a mut GhostToken<'brand>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a, 'brand, T: serde::Deserialize<'de>> serde::de::DeserializeSeed<'de> for GhostCellSeed<'a, 'brand, T> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        *self.cell.borrow_mut(self.token) = T::deserialize(deserializer)?;
+
+        Ok(())
+    }
+}
+
+/// Formats the same placeholder as the `fmt::Debug` implementation, for the same reason.
+///
+/// Use [`GhostCell::format_with`] to format the content of the cell.
+#[cfg(feature = "defmt")]
+impl<'brand, T: ?Sized> defmt::Format for GhostCell<'brand, T> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "GhostCell {{ .. }}")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'brand, T: ?Sized + defmt::Format> GhostCell<'brand, T> {
+    /// Returns a wrapper, implementing `defmt::Format`, which formats the content of the cell.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell};
+    ///
+    /// fn assert_format<T: defmt::Format>(_: &T) {}
+    ///
+    /// GhostToken::new(|token| {
+    ///     let cell = GhostCell::new(42u32);
+    ///
+    ///     assert_format(&cell);
+    ///     assert_format(&cell.format_with(&token));
+    /// });
+    /// ```
+    pub fn format_with<'a>(&'a self, token: &'a GhostToken<'brand>) -> GhostCellFormat<'a, T> {
+        GhostCellFormat(self.borrow(token))
+    }
+}
+
+/// Formats the content of a `GhostCell`, see [`GhostCell::format_with`].
+#[cfg(feature = "defmt")]
+pub struct GhostCellFormat<'a, T: ?Sized>(&'a T);
+
+#[cfg(feature = "defmt")]
+impl<'a, T: ?Sized + defmt::Format> defmt::Format for GhostCellFormat<'a, T> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "GhostCell {{ value: {} }}", self.0)
+    }
+}
+
+//
+//  Implementation
+//
+
+type InvariantLifetime<'brand> = PhantomData<fn(&'brand ()) -> &'brand ()>;
+
+#[doc(hidden)]
+pub mod compile_tests {
+
+/// ```compile_fail
 /// use ghost_cell::{GhostToken, GhostCell};
 ///
 /// GhostToken::new(|token| token);
@@ -500,4 +1554,18 @@ pub fn cell_get_mut_borrows_cell_mutably() {}
 /// ```
 pub fn cell_from_mut_borrows_value_mutably() {}
 
+/// ```compile_fail,E0502
+/// use ghost_cell::{GhostToken, GhostCell};
+///
+/// GhostToken::new(|token| {
+///     let mut array = [42, 43];
+///
+///     let cells = GhostCell::from_mut_slice(&mut array);
+///
+///     assert_eq!(42, array[0]);
+///     assert_eq!(42, *cells[0].borrow(&token));
+/// });
+/// ```
+pub fn cell_from_mut_slice_borrows_slice_mutably() {}
+
 } // mod compile_tests
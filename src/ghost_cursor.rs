@@ -41,6 +41,9 @@
 
 use core::ptr::NonNull;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use super::{GhostCell, GhostToken};
 
 /// A `GhostCursor`, to navigate across a web of `GhostCell`s.
@@ -135,6 +138,64 @@ impl<'a, 'brand, T: ?Sized> GhostCursor<'a, 'brand, T> {
         self.cell.map(|cell| cell.borrow(token))
     }
 
+    /// Returns references to the inner values of up to `n` cells, starting from the current cell and following
+    /// `next` to reach each subsequent cell, without moving the cursor.
+    ///
+    /// Fewer than `n` references are returned if there is no current cell, or `next` returns no cell.
+    ///
+    /// #   Example
+    ///
+    /// ```
+    /// use ghost_cell::{GhostCell, GhostCursor, GhostToken};
+    ///
+    /// struct Node<'brand> {
+    ///     value: i32,
+    ///     next: Option<Box<GhostCell<'brand, Node<'brand>>>>,
+    /// }
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let head = (1..=5).rev().fold(None, |next, value| Some(Box::new(GhostCell::new(Node { value, next }))));
+    ///     let head = head.expect("5 nodes");
+    ///
+    ///     let cursor = GhostCursor::new(&mut token, Some(&*head));
+    ///
+    ///     let ahead = cursor.lookahead(3, |node| node.next.as_deref());
+    ///     let values: Vec<_> = ahead.iter().map(|node| node.value).collect();
+    ///     assert_eq!(vec![1, 2, 3], values);
+    ///
+    ///     let ahead = cursor.lookahead(8, |node| node.next.as_deref());
+    ///     assert_eq!(5, ahead.len());
+    ///
+    ///     let ahead = cursor.lookahead(usize::MAX, |node| node.next.as_deref());
+    ///     assert_eq!(5, ahead.len());
+    ///
+    ///     assert_eq!(Some(1), cursor.borrow().map(|node| node.value));
+    /// });
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn lookahead<F>(&self, n: usize, mut next: F) -> Vec<&T>
+    where
+        F: FnMut(&T) -> Option<&GhostCell<'brand, T>>,
+    {
+        //  Only shared references are materialized, and `self` is borrowed for as long as they live.
+        let token = self.token();
+
+        //  `n` is only an upper bound on the length of the chain, hence not reserved upfront.
+        let mut result = Vec::new();
+        let mut current = self.cell;
+
+        while result.len() < n {
+            let Some(cell) = current else { break };
+
+            let value = cell.borrow(token);
+            result.push(value);
+
+            current = next(value);
+        }
+
+        result
+    }
+
     /// Returns a mutable reference to the inner value of the current cell.
     ///
     /// #   Example
@@ -212,6 +273,63 @@ impl<'a, 'brand, T: ?Sized> GhostCursor<'a, 'brand, T> {
         Ok(())
     }
 
+    /// Attempts to move from the current cell to another cell, derived from it, possibly mutating the current element.
+    ///
+    /// Unlike `move_mut`, `fun` receives a mutable reference to the current element. The cell it returns must be
+    /// derived from this reference, and the borrow of the current element ends as soon as the cursor moves.
+    ///
+    /// Returns whether the cursor moved, that is `false` if either:
+    /// -   There is no current cell.
+    /// -   `fun` returns no cell.
+    ///
+    /// If the cursor did not move, the current cell is not modified, though its element may have been by `fun`.
+    ///
+    /// #   Example
+    ///
+    /// ```
+    /// use ghost_cell::{GhostCell, GhostCursor, GhostToken};
+    ///
+    /// struct Node<'brand> {
+    ///     value: i32,
+    ///     next: Option<Box<GhostCell<'brand, Node<'brand>>>>,
+    /// }
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let head = (1..=4).rev().fold(None, |next, value| Some(Box::new(GhostCell::new(Node { value, next }))));
+    ///     let head = head.expect("4 nodes");
+    ///
+    ///     let mut cursor = GhostCursor::new(&mut token, Some(&*head));
+    ///
+    ///     let mut steps = 0;
+    ///
+    ///     while cursor.advance_mut(|node| { node.value *= 10; node.next.as_deref() }) {
+    ///         steps += 1;
+    ///     }
+    ///
+    ///     assert_eq!(3, steps);
+    ///     //  The last node is mutated too, even though the cursor does not move past it.
+    ///     assert_eq!(Some(40), cursor.borrow().map(|node| node.value));
+    ///     assert_eq!(10, head.borrow(&token).value);
+    /// });
+    /// ```
+    pub fn advance_mut<F>(&mut self, fun: F) -> bool
+    where
+        F: FnOnce(&mut T) -> Option<&GhostCell<'brand, T>>,
+    {
+        //  Safety:
+        //  -   Borrows `self` mutably, therefore ensuring that no borrow of the token exists.
+        //  -   The mutable borrow of the current element ends once `fun` returns; only the shared reference to the next
+        //      cell derived from it remains, and the next cell's element may only be accessed through the cursor.
+        let token = unsafe { as_mut(self.token) };
+
+        let Some(cell) = self.cell else { return false };
+        let Some(cell) = fun(cell.borrow_mut(token)) else { return false };
+
+        self.cell = Some(cell);
+
+        true
+    }
+
     /// Attempts to move from the current cell to another cell, derived from it.
     ///
     /// Returns an error if either:
@@ -364,4 +482,47 @@ pub fn cursor_into_parts_second_part_leaves_token_borrowed_mutably() {}
 /// ```
 pub fn cursor_move_mut_noescape() {}
 
+/// ```compile_fail,E0521
+/// use core::cell::Cell;
+/// use ghost_cell::{GhostCell, GhostCursor, GhostToken};
+///
+/// GhostToken::new(|mut token| {
+///     let cell = GhostCell::new(1);
+///     let leak = Cell::new(None);
+///     let mut cursor = GhostCursor::new(&mut token, Some(&cell));
+///
+///     cursor.advance_mut(|value| {
+///         leak.set(Some(value));   //  Fail, `value` cannot escape the closure body.
+///         None
+///     });
+///
+///     //  If `value` escaped, there would be two mutable references to the same element -- this is unsound.
+///     let value: &mut i32 = leak.take().unwrap();
+///     *cursor.borrow_mut().unwrap() = 42;
+///     *value = 33;
+/// })
+/// ```
+pub fn cursor_advance_mut_noescape() {}
+
+/// ```compile_fail,E0499
+/// use ghost_cell::{GhostCell, GhostCursor, GhostToken};
+///
+/// struct Node<'brand> {
+///     value: i32,
+///     next: Option<Box<GhostCell<'brand, Node<'brand>>>>,
+/// }
+///
+/// GhostToken::new(|mut token| {
+///     let two = Node { value: 2, next: None };
+///     let one = GhostCell::new(Node { value: 1, next: Some(Box::new(GhostCell::new(two))) });
+///
+///     let mut cursor = GhostCursor::new(&mut token, Some(&one));
+///
+///     let first = cursor.borrow_mut().unwrap();
+///     cursor.advance_mut(|node| node.next.as_deref());   //  Fail, `first` still borrows the cursor.
+///     first.value = 3;
+/// })
+/// ```
+pub fn cursor_advance_mut_ends_previous_borrow() {}
+
 } // mod compile_tests
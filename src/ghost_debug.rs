@@ -0,0 +1,150 @@
+//! Debugging helpers for webs of `GhostCell`s.
+//!
+//! `to_dot` walks a graph of `GhostCell`s from a starting cell and renders it in the DOT language of Graphviz.
+
+use core::fmt::{self, Write};
+
+use alloc::{collections::{BTreeMap, VecDeque}, string::String};
+
+use crate::ghost_cell::{GhostCell, GhostToken};
+
+/// Renders the graph of `GhostCell`s reachable from `start` as a DOT digraph.
+///
+/// The graph is walked breadth-first, using `children` to list the cells pointed to by a value. Each cell is visited
+/// once, identified by its address, hence cycles are rendered as such. Nodes are named `n0`, `n1`, ... in the order
+/// they are discovered, starting from `start`, and labelled with the `Debug` representation of their value.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_debug};
+///
+/// GhostToken::new(|token| {
+///     let graph = [
+///         GhostCell::new(("root", vec![1, 2])),
+///         GhostCell::new(("left", vec![])),
+///         GhostCell::new(("right", vec![1])),
+///     ];
+///
+///     let dot = ghost_debug::to_dot(&graph[0], &token, |node| node.1.iter().map(|&index| &graph[index]));
+///
+///     let expected = r#"digraph {
+///     n0 [label="(\"root\", [1, 2])"];
+///     n0 -> n1;
+///     n0 -> n2;
+///     n1 [label="(\"left\", [])"];
+///     n2 [label="(\"right\", [1])"];
+///     n2 -> n1;
+/// }
+/// "#;
+///
+///     assert_eq!(expected, dot);
+/// });
+/// ```
+pub fn to_dot<'a, 'brand, T, I, F>(
+    start: &'a GhostCell<'brand, T>,
+    token: &'a GhostToken<'brand>,
+    mut children: F,
+) -> String
+where
+    T: fmt::Debug,
+    I: IntoIterator<Item = &'a GhostCell<'brand, T>>,
+    F: FnMut(&'a T) -> I,
+{
+    let mut ids = BTreeMap::new();
+    let mut queue = VecDeque::new();
+
+    ids.insert(start.as_ptr() as *const T, 0usize);
+    queue.push_back((0usize, start));
+
+    let mut dot = String::from("digraph {\n");
+
+    while let Some((id, cell)) = queue.pop_front() {
+        let value = cell.borrow(token);
+
+        write!(dot, "    n{} [label=\"", id).expect("writing to a String cannot fail");
+        write!(Escape(&mut dot), "{:?}", value).expect("writing to a String cannot fail");
+        dot.push_str("\"];\n");
+
+        for child in children(value) {
+            let next = ids.len();
+
+            let child_id = *ids.entry(child.as_ptr() as *const T).or_insert_with(|| {
+                queue.push_back((next, child));
+                next
+            });
+
+            writeln!(dot, "    n{} -> n{};", id, child_id).expect("writing to a String cannot fail");
+        }
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+//
+//  Implementation
+//
+
+//  Escapes the text written through it for use within a double-quoted DOT string.
+struct Escape<'a>(&'a mut String);
+
+impl fmt::Write for Escape<'_> {
+    fn write_str(&mut self, label: &str) -> fmt::Result {
+        for c in label.chars() {
+            match c {
+                '"' => self.0.push_str("\\\""),
+                '\\' => self.0.push_str("\\\\"),
+                '\n' => self.0.push_str("\\n"),
+                _ => self.0.push(c),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+use alloc::vec::Vec;
+
+use super::*;
+
+#[test]
+fn to_dot_three_nodes_with_cycle() {
+    GhostToken::new(|token| {
+        let cells: Vec<GhostCell<(char, Vec<usize>)>> = [('a', vec![1, 2]), ('b', vec![2]), ('c', vec![0])]
+            .into_iter()
+            .map(GhostCell::new)
+            .collect();
+
+        let dot = to_dot(&cells[0], &token, |node| node.1.iter().map(|&index| &cells[index]));
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+
+        for node in ["n0 [label=\"('a', [1, 2])\"];", "n1 [label=\"('b', [2])\"];", "n2 [label=\"('c', [0])\"];"] {
+            assert!(dot.contains(node), "{} not in {}", node, dot);
+        }
+
+        for edge in ["n0 -> n1;", "n0 -> n2;", "n1 -> n2;", "n2 -> n0;"] {
+            assert!(dot.contains(edge), "{} not in {}", edge, dot);
+        }
+
+        assert_eq!(3, dot.matches("[label=").count());
+        assert_eq!(4, dot.matches(" -> ").count());
+    });
+}
+
+#[test]
+fn escape_label() {
+    let mut dot = String::new();
+
+    Escape(&mut dot).write_str("say \"boo\"\n\\").expect("writing to a String cannot fail");
+
+    assert_eq!("say \\\"boo\\\"\\n\\\\", dot);
+}
+
+} // mod tests
@@ -0,0 +1,127 @@
+//! A `GhostFrozen` freezes a slice of `GhostCell`s, after which their contents may only be read.
+//!
+//! This supports a build-then-read workflow: the cells are built and mutated with the token, then the token is handed
+//! over to a `GhostFrozen`, statically preventing any further mutation of any cell sharing its brand.
+
+use crate::ghost_borrow::GhostBorrow;
+use crate::ghost_cell::{GhostCell, GhostToken};
+
+/// A read-only view of a slice of `GhostCell`s, which owns the token of their brand.
+///
+/// As the token is consumed, no cell of this brand may be mutably borrowed ever again, while any of them may still be
+/// borrowed immutably through the `GhostFrozen`.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostFrozen};
+///
+/// GhostToken::new(|mut token| {
+///     let cells: Vec<_> = (0..4).map(GhostCell::new).collect();
+///
+///     for cell in &cells {
+///         *cell.borrow_mut(&mut token) *= 10;
+///     }
+///
+///     let frozen = GhostFrozen::new(&cells, token);
+///
+///     assert_eq!(&[0, 10, 20, 30], frozen.as_slice());
+///     assert_eq!(Some(&20), frozen.get(2));
+/// });
+/// ```
+pub struct GhostFrozen<'a, 'brand, T> {
+    cells: &'a [GhostCell<'brand, T>],
+    token: GhostToken<'brand>,
+}
+
+#[forbid(unsafe_code)]
+impl<'a, 'brand, T> GhostFrozen<'a, 'brand, T> {
+    /// Freezes `cells`, consuming the token.
+    pub fn new(cells: &'a [GhostCell<'brand, T>], token: GhostToken<'brand>) -> Self {
+        Self { cells, token }
+    }
+
+    /// Returns the contents of the frozen cells.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells.borrow(&self.token)
+    }
+
+    /// Returns the content of the frozen cell at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Immutably borrows any `GhostCell` of the same brand, frozen or not.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostCell, GhostFrozen};
+    ///
+    /// GhostToken::new(|token| {
+    ///     let cells = [GhostCell::new(1), GhostCell::new(2)];
+    ///     let name = GhostCell::new("pair");
+    ///
+    ///     let frozen = GhostFrozen::new(&cells, token);
+    ///
+    ///     assert_eq!("pair", *frozen.borrow(&name));
+    /// });
+    /// ```
+    pub fn borrow<'b, U: ?Sized>(&'b self, cell: &'b GhostCell<'brand, U>) -> &'b U {
+        cell.borrow(&self.token)
+    }
+
+    /// Returns the number of frozen cells.
+    pub fn len(&self) -> usize { self.cells.len() }
+
+    /// Returns whether there are no frozen cells.
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+}
+
+#[doc(hidden)]
+pub mod compile_tests {
+
+/// ```compile_fail,E0382
+/// use ghost_cell::{GhostToken, GhostCell, GhostFrozen};
+///
+/// GhostToken::new(|mut token| {
+///     let cells = [GhostCell::new(1), GhostCell::new(2)];
+///
+///     let frozen = GhostFrozen::new(&cells, token);
+///
+///     *cells[0].borrow_mut(&mut token) = 3;   //  Fail, token moved into `frozen`.
+///
+///     assert_eq!(Some(&3), frozen.get(0));
+/// });
+/// ```
+pub fn frozen_consumes_token() {}
+
+} // mod compile_tests
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn build_freeze_read() {
+    let value = GhostToken::new(|mut token| {
+        let cells: Vec<_> = (1..=5).map(GhostCell::new).collect();
+
+        for (i, cell) in cells.iter().enumerate() {
+            *cell.borrow_mut(&mut token) += i * 10;
+        }
+
+        let frozen = GhostFrozen::new(&cells, token);
+        assert_eq!(5, frozen.len());
+        assert!(!frozen.is_empty());
+        assert_eq!(None, frozen.get(5));
+        assert_eq!(Some(&23), frozen.get(2));
+        assert_eq!(23, *frozen.borrow(&cells[2]));
+
+        frozen.as_slice().iter().sum::<usize>()
+    });
+    assert_eq!(115, value);
+}
+
+} // mod tests
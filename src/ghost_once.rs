@@ -0,0 +1,135 @@
+//! A `GhostOnceCell` is a `GhostCell` which may be set at most once, through the `GhostToken`.
+//!
+//! This supports lazily initialized branded singletons within the scope of a `GhostToken`.
+
+use crate::ghost_cell::{GhostCell, GhostToken};
+
+/// A branded cell which may be set at most once.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostOnceCell};
+///
+/// GhostToken::new(|mut token| {
+///     let once = GhostOnceCell::new();
+///
+///     assert_eq!(None, once.get(&token));
+///
+///     assert_eq!(Ok(()), once.set(42, &mut token));
+///     assert_eq!(Err(33), once.set(33, &mut token));
+///
+///     assert_eq!(Some(&42), once.get(&token));
+/// });
+/// ```
+pub struct GhostOnceCell<'brand, T> {
+    value: GhostCell<'brand, Option<T>>,
+}
+
+#[forbid(unsafe_code)]
+impl<'brand, T> GhostOnceCell<'brand, T> {
+    /// Creates an empty cell.
+    pub const fn new() -> Self {
+        Self { value: GhostCell::new(None) }
+    }
+
+    /// Sets the content of the cell to `value`, if it is empty, otherwise returns `value` back.
+    pub fn set(&self, value: T, token: &mut GhostToken<'brand>) -> Result<(), T> {
+        let slot = self.value.borrow_mut(token);
+
+        if slot.is_some() {
+            return Err(value);
+        }
+
+        *slot = Some(value);
+
+        Ok(())
+    }
+
+    /// Returns a reference to the content of the cell, if set.
+    pub fn get<'a>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        self.value.borrow(token).as_ref()
+    }
+
+    /// Returns a reference to the content of the cell, setting it to the result of `f` first if it is empty.
+    ///
+    /// #   Example
+    ///
+    /// ```rust
+    /// use ghost_cell::{GhostToken, GhostOnceCell};
+    ///
+    /// GhostToken::new(|mut token| {
+    ///     let once = GhostOnceCell::new();
+    ///
+    ///     assert_eq!("first", *once.get_or_init(&mut token, || "first"));
+    ///     assert_eq!("first", *once.get_or_init(&mut token, || "second"));
+    /// });
+    /// ```
+    pub fn get_or_init<'a, F>(&'a self, token: &'a mut GhostToken<'brand>, f: F) -> &'a T
+    where
+        F: FnOnce() -> T,
+    {
+        self.value.borrow_mut(token).get_or_insert_with(f)
+    }
+
+    /// Returns a mutable reference to the content of the cell, if set.
+    ///
+    /// This call borrows the cell mutably, hence no token is required.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.value.get_mut().as_mut()
+    }
+
+    /// Turns the cell into its content, if set.
+    pub fn into_inner(self) -> Option<T> { self.value.into_inner() }
+}
+
+impl<'brand, T> Default for GhostOnceCell<'brand, T> {
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn first_set_succeeds() {
+    GhostToken::new(|mut token| {
+        let once = GhostOnceCell::new();
+
+        assert_eq!(Ok(()), once.set(String::from("ghost"), &mut token));
+        assert_eq!(Some("ghost"), once.get(&token).map(String::as_str));
+    });
+}
+
+#[test]
+fn second_set_fails() {
+    let value = GhostToken::new(|mut token| {
+        let once = GhostOnceCell::new();
+
+        once.set(1, &mut token).unwrap();
+        assert_eq!(Err(2), once.set(2, &mut token));
+        assert_eq!(Some(&1), once.get(&token));
+
+        once.into_inner()
+    });
+    assert_eq!(Some(1), value);
+}
+
+#[test]
+fn get_or_init_once() {
+    GhostToken::new(|mut token| {
+        let mut once = GhostOnceCell::new();
+        let mut calls = 0;
+
+        assert_eq!(7, *once.get_or_init(&mut token, || { calls += 1; 7 }));
+        assert_eq!(7, *once.get_or_init(&mut token, || { calls += 1; 8 }));
+        assert_eq!(1, calls);
+
+        *once.get_mut().expect("initialized") += 1;
+        assert_eq!(Err(9), once.set(9, &mut token));
+        assert_eq!(Some(&8), once.get(&token));
+    });
+}
+
+} // mod tests
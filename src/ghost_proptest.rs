@@ -0,0 +1,131 @@
+//! `proptest` strategies producing `GhostCell`s, and arenas of `GhostCell`s.
+//!
+//! #   Usage
+//!
+//! A `GhostCell` can only be used alongside the `GhostToken` sharing its brand, hence properties must run _inside_ the
+//! `GhostToken::new` closure, by driving a `TestRunner` by hand rather than using the `proptest!` macro.
+//!
+//! The property being a `Fn`, mutable access to the token requires wrapping it, for example in a `RefCell`.
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//!
+//! use ghost_cell::{GhostToken, ghost_borrow, ghost_proptest};
+//! use proptest::{prop_assert_eq, test_runner::TestRunner};
+//!
+//! GhostToken::new(|token| {
+//!     let token = RefCell::new(token);
+//!
+//!     let strategy = ghost_proptest::arena_of(0..100i64, 0..16);
+//!
+//!     TestRunner::default().run(&strategy, |arena| {
+//!         let expected: i64 = arena.iter().map(|cell| 2 * *cell.borrow(&token.borrow())).sum();
+//!
+//!         for cell in &arena {
+//!             *cell.borrow_mut(&mut token.borrow_mut()) *= 2;
+//!         }
+//!
+//!         let actual = ghost_borrow::fold(&arena, 0, &token.borrow(), |acc, x| acc + x);
+//!         prop_assert_eq!(expected, actual);
+//!
+//!         Ok(())
+//!     }).unwrap();
+//! });
+//! ```
+
+use alloc::vec::Vec;
+
+use proptest::{collection::SizeRange, strategy::Strategy};
+
+use crate::ghost_cell::GhostCell;
+
+/// Returns a strategy producing `GhostCell`s wrapping the values produced by `strategy`.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, ghost_proptest};
+/// use proptest::{prop_assert, test_runner::TestRunner};
+///
+/// GhostToken::new(|token| {
+///     let strategy = ghost_proptest::ghost_cell_of(0..10u32);
+///
+///     TestRunner::default().run(&strategy, |cell| {
+///         prop_assert!(*cell.borrow(&token) < 10);
+///
+///         Ok(())
+///     }).unwrap();
+/// });
+/// ```
+pub fn ghost_cell_of<'brand, S>(strategy: S) -> impl Strategy<Value = GhostCell<'brand, S::Value>>
+where
+    S: Strategy,
+{
+    strategy.prop_map(GhostCell::new)
+}
+
+/// Returns a strategy producing arenas of `GhostCell`s, whose length is within `size`, wrapping the values produced by
+/// `strategy`.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, ghost_proptest};
+/// use proptest::{prop_assert, test_runner::TestRunner};
+///
+/// GhostToken::new(|token| {
+///     let strategy = ghost_proptest::arena_of(0..10u32, 1..8);
+///
+///     TestRunner::default().run(&strategy, |arena| {
+///         prop_assert!(!arena.is_empty() && arena.len() < 8);
+///         prop_assert!(arena.iter().all(|cell| *cell.borrow(&token) < 10));
+///
+///         Ok(())
+///     }).unwrap();
+/// });
+/// ```
+pub fn arena_of<'brand, S>(
+    strategy: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<GhostCell<'brand, S::Value>>>
+where
+    S: Strategy,
+{
+    proptest::collection::vec(ghost_cell_of(strategy), size)
+}
+
+#[cfg(test)]
+mod tests {
+
+use core::cell::RefCell;
+
+use proptest::{prop_assert_eq, test_runner::TestRunner};
+
+use super::*;
+use crate::ghost_cell::GhostToken;
+
+#[test]
+fn arena_reverse_twice() {
+    GhostToken::new(|token| {
+        let token = RefCell::new(token);
+
+        TestRunner::default().run(&arena_of(proptest::num::i32::ANY, 0..32), |arena| {
+            let original: Vec<i32> = arena.iter().map(|cell| *cell.borrow(&token.borrow())).collect();
+
+            for _ in 0..2 {
+                let values: Vec<i32> = arena.iter().rev().map(|cell| *cell.borrow(&token.borrow())).collect();
+
+                for (cell, value) in arena.iter().zip(values) {
+                    cell.replace(value, &mut token.borrow_mut());
+                }
+            }
+
+            let current: Vec<i32> = arena.iter().map(|cell| *cell.borrow(&token.borrow())).collect();
+            prop_assert_eq!(original, current);
+
+            Ok(())
+        }).unwrap();
+    });
+}
+
+} // mod tests
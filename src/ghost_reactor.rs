@@ -0,0 +1,118 @@
+//! A `GhostReactor` records which cells of an arena of `GhostCell`s were mutated, for incremental recomputation.
+//!
+//! Cells are marked dirty either explicitly, with `mark_dirty`, or implicitly when mutably borrowed through the
+//! reactor, with `get_mut`. Downstream computations then `drain_dirty` to learn which cells to recompute.
+
+use core::mem;
+
+use std::{collections::BTreeSet, vec::Vec};
+
+use crate::ghost_cell::{GhostCell, GhostToken};
+
+/// A branded record of the indices of mutated cells within an arena of `GhostCell`s.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, GhostReactor};
+///
+/// GhostToken::new(|mut token| {
+///     let inputs: Vec<_> = (0..4).map(GhostCell::new).collect();
+///     let reactor = GhostReactor::new();
+///
+///     if let Some(input) = reactor.get_mut(&inputs, 3, &mut token) {
+///         *input = 30;
+///     }
+///
+///     assert_eq!(vec![3], reactor.drain_dirty(&mut token));
+///     assert_eq!(30, *inputs[3].borrow(&token));
+/// });
+/// ```
+pub struct GhostReactor<'brand> {
+    dirty: GhostCell<'brand, BTreeSet<usize>>,
+}
+
+#[forbid(unsafe_code)]
+impl<'brand> GhostReactor<'brand> {
+    /// Creates a reactor, with no dirty cell.
+    pub fn new() -> Self {
+        Self { dirty: GhostCell::new(BTreeSet::new()) }
+    }
+
+    /// Marks the cell at `index` as dirty.
+    pub fn mark_dirty(&self, index: usize, token: &mut GhostToken<'brand>) {
+        self.dirty.borrow_mut(token).insert(index);
+    }
+
+    /// Returns whether the cell at `index` is dirty.
+    pub fn is_dirty(&self, index: usize, token: &GhostToken<'brand>) -> bool {
+        self.dirty.borrow(token).contains(&index)
+    }
+
+    /// Returns a mutable reference to the content of the cell at `index`, marking it as dirty, if it exists.
+    pub fn get_mut<'a, T>(
+        &self,
+        cells: &'a [GhostCell<'brand, T>],
+        index: usize,
+        token: &'a mut GhostToken<'brand>,
+    ) -> Option<&'a mut T> {
+        let cell = cells.get(index)?;
+
+        self.mark_dirty(index, token);
+
+        Some(cell.borrow_mut(token))
+    }
+
+    /// Returns the indices of the dirty cells, in increasing order, and marks all cells as clean.
+    pub fn drain_dirty(&self, token: &mut GhostToken<'brand>) -> Vec<usize> {
+        mem::take(self.dirty.borrow_mut(token)).into_iter().collect()
+    }
+}
+
+impl<'brand> Default for GhostReactor<'brand> {
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn mutate_two_cells_then_drain() {
+    GhostToken::new(|mut token| {
+        let cells: Vec<_> = (0..5).map(GhostCell::new).collect();
+        let reactor = GhostReactor::new();
+
+        *reactor.get_mut(&cells, 4, &mut token).expect("in bounds") += 40;
+        *reactor.get_mut(&cells, 1, &mut token).expect("in bounds") += 10;
+        *reactor.get_mut(&cells, 4, &mut token).expect("in bounds") += 400;
+
+        assert!(reactor.get_mut(&cells, 5, &mut token).is_none());
+
+        assert!(reactor.is_dirty(1, &token));
+        assert!(!reactor.is_dirty(2, &token));
+        assert!(!reactor.is_dirty(5, &token));
+
+        assert_eq!(vec![1, 4], reactor.drain_dirty(&mut token));
+        assert_eq!(Vec::<usize>::new(), reactor.drain_dirty(&mut token));
+
+        assert_eq!(11, *cells[1].borrow(&token));
+        assert_eq!(444, *cells[4].borrow(&token));
+    });
+}
+
+#[test]
+fn mark_dirty_explicitly() {
+    GhostToken::new(|mut token| {
+        let reactor = GhostReactor::new();
+
+        reactor.mark_dirty(7, &mut token);
+        reactor.mark_dirty(3, &mut token);
+
+        assert_eq!(vec![3, 7], reactor.drain_dirty(&mut token));
+        assert!(!reactor.is_dirty(7, &token));
+    });
+}
+
+} // mod tests
@@ -0,0 +1,117 @@
+//! A `GhostRegistry` stores at most one value per type, all accessible through the same `GhostToken`.
+//!
+//! This is a branded, heterogeneous, store of components, keyed by their `TypeId`.
+
+use core::any::{Any, TypeId};
+
+use std::{boxed::Box, collections::HashMap};
+
+use crate::ghost_cell::{GhostCell, GhostToken};
+
+/// A branded store of values of heterogeneous types, holding at most one value per type.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostRegistry};
+///
+/// struct Position(i32, i32);
+/// struct Name(&'static str);
+///
+/// GhostToken::new(|mut token| {
+///     let registry = GhostRegistry::new();
+///
+///     registry.insert(Position(1, 2), &mut token);
+///     registry.insert(Name("ghost"), &mut token);
+///
+///     if let Some(position) = registry.get_mut::<Position>(&mut token) {
+///         position.0 += 1;
+///     }
+///
+///     let position = registry.get::<Position>(&token).expect("inserted");
+///     let name = registry.get::<Name>(&token).expect("inserted");
+///
+///     assert_eq!((2, 2, "ghost"), (position.0, position.1, name.0));
+/// });
+/// ```
+pub struct GhostRegistry<'brand> {
+    values: GhostCell<'brand, HashMap<TypeId, Box<dyn Any>>>,
+}
+
+impl<'brand> GhostRegistry<'brand> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self { values: GhostCell::new(HashMap::new()) }
+    }
+
+    /// Inserts `value`, returning the previously registered value of the same type, if any.
+    pub fn insert<T: 'static>(&self, value: T, token: &mut GhostToken<'brand>) -> Option<T> {
+        let previous = self.values.borrow_mut(token).insert(TypeId::of::<T>(), Box::new(value));
+
+        previous.map(downcast)
+    }
+
+    /// Returns a reference to the registered value of type `T`, if any.
+    pub fn get<'a, T: 'static>(&'a self, token: &'a GhostToken<'brand>) -> Option<&'a T> {
+        self.values.borrow(token).get(&TypeId::of::<T>()).and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the registered value of type `T`, if any.
+    pub fn get_mut<'a, T: 'static>(&'a self, token: &'a mut GhostToken<'brand>) -> Option<&'a mut T> {
+        self.values.borrow_mut(token).get_mut(&TypeId::of::<T>()).and_then(|value| value.downcast_mut())
+    }
+
+    /// Removes the registered value of type `T`, if any, and returns it.
+    pub fn remove<T: 'static>(&self, token: &mut GhostToken<'brand>) -> Option<T> {
+        self.values.borrow_mut(token).remove(&TypeId::of::<T>()).map(downcast)
+    }
+}
+
+impl<'brand> Default for GhostRegistry<'brand> {
+    fn default() -> Self { Self::new() }
+}
+
+//
+//  Implementation
+//
+
+//  Values are keyed by their `TypeId`, hence the downcast cannot fail.
+fn downcast<T: 'static>(value: Box<dyn Any>) -> T {
+    *value.downcast().expect("values are keyed by their TypeId")
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn insert_and_get_two_types() {
+    GhostToken::new(|mut token| {
+        let registry = GhostRegistry::new();
+
+        assert_eq!(None, registry.insert(42u32, &mut token));
+        assert_eq!(None, registry.insert(String::from("ghost"), &mut token));
+
+        assert_eq!(Some(&42u32), registry.get::<u32>(&token));
+        assert_eq!(Some("ghost"), registry.get::<String>(&token).map(String::as_str));
+        assert_eq!(None, registry.get::<i32>(&token));
+    });
+}
+
+#[test]
+fn insert_replaces_same_type() {
+    GhostToken::new(|mut token| {
+        let registry = GhostRegistry::new();
+
+        registry.insert(1u8, &mut token);
+        assert_eq!(Some(1u8), registry.insert(2u8, &mut token));
+
+        *registry.get_mut::<u8>(&mut token).expect("inserted") += 1;
+
+        assert_eq!(Some(3u8), registry.remove::<u8>(&mut token));
+        assert_eq!(None, registry.get::<u8>(&token));
+    });
+}
+
+} // mod tests
@@ -0,0 +1,80 @@
+//! Summary statistics over arenas of `GhostCell`s.
+
+use crate::{ghost_borrow::GhostBorrow, ghost_cell::{GhostCell, GhostToken}};
+
+/// Summary statistics of a set of values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    /// The least value.
+    pub min: f64,
+    /// The greatest value.
+    pub max: f64,
+    /// The arithmetic mean of the values.
+    pub mean: f64,
+    /// The number of values.
+    pub count: usize,
+}
+
+/// Computes the summary statistics of the values of `cells`, in a single pass, or `None` if `cells` is empty.
+///
+/// As per `f64::min` and `f64::max`, NaN values are ignored by `min` and `max`, though they propagate to `mean`.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_stats};
+///
+/// GhostToken::new(|token| {
+///     let cells: Vec<_> = [2.0, 8.0, 5.0].into_iter().map(GhostCell::new).collect();
+///
+///     let stats = ghost_stats::summary(&cells, &token).expect("non-empty");
+///
+///     assert_eq!((2.0, 8.0, 5.0, 3), (stats.min, stats.max, stats.mean, stats.count));
+///
+///     assert_eq!(None, ghost_stats::summary(&cells[..0], &token));
+/// });
+/// ```
+pub fn summary<'brand>(cells: &[GhostCell<'brand, f64>], token: &GhostToken<'brand>) -> Option<Stats> {
+    let values = cells.borrow(token);
+
+    let (first, rest) = values.split_first()?;
+
+    let (min, max, sum) = rest.iter().fold((*first, *first, *first), |(min, max, sum), x| {
+        (min.min(*x), max.max(*x), sum + x)
+    });
+
+    let count = values.len();
+
+    Some(Stats { min, max, mean: sum / count as f64, count })
+}
+
+#[cfg(test)]
+mod tests {
+
+use super::*;
+
+#[test]
+fn summary_small_arena() {
+    GhostToken::new(|token| {
+        let cells: Vec<_> = [3.5, -1.0, 4.0, 10.5, 3.0].into_iter().map(GhostCell::new).collect();
+
+        let stats = summary(&cells, &token).expect("non-empty");
+
+        assert_eq!(-1.0, stats.min);
+        assert_eq!(10.5, stats.max);
+        assert_eq!(4.0, stats.mean);
+        assert_eq!(5, stats.count);
+    });
+}
+
+#[test]
+fn summary_single_and_empty() {
+    GhostToken::new(|token| {
+        let cells = [GhostCell::new(7.25)];
+
+        assert_eq!(Some(Stats { min: 7.25, max: 7.25, mean: 7.25, count: 1 }), summary(&cells, &token));
+        assert_eq!(None, summary(&cells[..0], &token));
+    });
+}
+
+} // mod tests
@@ -39,22 +39,92 @@
 //  Lints.
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod ghost_cell;
 
 pub use self::ghost_cell::{GhostCell, GhostToken};
 
 pub mod ghost_borrow;
 
-pub use self::ghost_borrow::GhostBorrow;
+pub use self::ghost_borrow::{GhostBorrow, GhostLengthError};
+
+pub mod ghost_frozen;
+
+pub use self::ghost_frozen::GhostFrozen;
+
+pub mod ghost_once;
+
+pub use self::ghost_once::GhostOnceCell;
+
+pub mod ghost_stats;
 
 #[cfg(feature = "experimental-multiple-mutable-borrows")]
 pub mod ghost_borrow_mut;
 
 #[cfg(feature = "experimental-multiple-mutable-borrows")]
-pub use self::ghost_borrow_mut::{GhostAliasingError, GhostBorrowMut};
+pub use self::ghost_borrow_mut::{GhostAliasingError, GhostBorrowMut, GhostIndexError};
 
 #[cfg(feature = "experimental-ghost-cursor")]
 pub mod ghost_cursor;
 
 #[cfg(feature = "experimental-ghost-cursor")]
-pub use self::ghost_cursor::GhostCursor;
\ No newline at end of file
+pub use self::ghost_cursor::GhostCursor;
+
+#[cfg(feature = "alloc")]
+pub mod ghost_debug;
+
+#[cfg(feature = "proptest")]
+pub mod ghost_proptest;
+
+#[cfg(feature = "std")]
+pub mod ghost_reactor;
+
+#[cfg(feature = "std")]
+pub use self::ghost_reactor::GhostReactor;
+
+#[cfg(feature = "std")]
+pub mod ghost_registry;
+
+#[cfg(feature = "std")]
+pub use self::ghost_registry::GhostRegistry;
+
+/// Borrows the `GhostCell` at index `i` of `arena`, akin to `arena[i]`, immutably or mutably depending on `token`.
+///
+/// -   `ghost_index!(arena, i, &token)` expands to `arena[i].borrow(&token)`.
+/// -   `ghost_index!(arena, i, &mut token)` expands to `arena[i].borrow_mut(&mut token)`.
+///
+/// The mutability is determined syntactically: if `token` is already a `&mut GhostToken`, pass `&mut *token` to borrow
+/// mutably.
+///
+/// #   Panics
+///
+/// Panics if `i` is out of bounds, as indexing does.
+///
+/// #   Example
+///
+/// ```rust
+/// use ghost_cell::{GhostToken, GhostCell, ghost_index};
+///
+/// GhostToken::new(|mut token| {
+///     let arena: Vec<_> = (0..4).map(GhostCell::new).collect();
+///
+///     *ghost_index!(arena, 2, &mut token) += 40;
+///
+///     assert_eq!(42, *ghost_index!(arena, 2, &token));
+///     assert_eq!(3, *ghost_index!(arena, arena.len() - 1, &token));
+/// });
+/// ```
+#[macro_export]
+macro_rules! ghost_index {
+    ($arena:expr, $index:expr, &mut $token:expr) => {
+        $arena[$index].borrow_mut(&mut $token)
+    };
+    ($arena:expr, $index:expr, $token:expr) => {
+        $arena[$index].borrow($token)
+    };
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+//! Holding a `GhostToken` across `.await` points.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use ghost_cell::{GhostCell, GhostToken};
+
+#[test]
+fn borrowed_token_across_await() {
+    GhostToken::new(|mut token| {
+        let cell = GhostCell::new(1);
+
+        let future = increment_twice(&cell, &mut token);
+        assert_send(&future);
+
+        assert_eq!(3, block_on(future));
+        assert_eq!(3, *cell.borrow(&token));
+    });
+}
+
+#[test]
+fn owned_token_across_await() {
+    GhostToken::new(|token| {
+        let cell = GhostCell::new(String::from("before"));
+
+        let future = async {
+            let mut token = token;
+
+            cell.borrow_mut(&mut token).push_str(", pending");
+            YieldNow(false).await;
+            cell.borrow_mut(&mut token).push_str(", after");
+
+            token
+        };
+        assert_send(&future);
+
+        let token = block_on(future);
+        assert_eq!("before, pending, after", cell.borrow(&token));
+    });
+}
+
+#[test]
+fn non_send_cell_across_await() {
+    GhostToken::new(|mut token| {
+        let cell = GhostCell::new(Rc::new(1));
+
+        //  `Rc` is neither `Send` nor `Sync`, hence the future is not `Send` either, yet still works.
+        let future = async {
+            let value = Rc::clone(cell.borrow(&token));
+            YieldNow(false).await;
+            *cell.borrow_mut(&mut token) = Rc::new(*value + 1);
+        };
+
+        block_on(future);
+        assert_eq!(2, **cell.borrow(&token));
+    });
+}
+
+async fn increment_twice<'brand>(cell: &GhostCell<'brand, i32>, token: &mut GhostToken<'brand>) -> i32 {
+    *cell.borrow_mut(token) += 1;
+    YieldNow(false).await;
+    *cell.borrow_mut(token) += 1;
+
+    *cell.borrow(token)
+}
+
+fn assert_send<T: Send>(_: &T) {}
+
+//  Returns `Poll::Pending` once, before completing.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut context = Context::from_waker(&waker);
+
+    let mut future = Box::pin(future);
+
+    loop {
+        if let Poll::Ready(result) = future.as_mut().poll(&mut context) {
+            return result;
+        }
+    }
+}
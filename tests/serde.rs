@@ -0,0 +1,82 @@
+//! Round-tripping `GhostCell`s through `serde`.
+
+#![cfg(feature = "serde")]
+
+use ghost_cell::{GhostBorrow, GhostCell, GhostToken};
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+
+#[test]
+fn round_trip_vec() {
+    GhostToken::new(|mut token| {
+        let cells: Vec<GhostCell<i32>> = (1..=4).map(GhostCell::new).collect();
+        *cells[2].borrow_mut(&mut token) = 42;
+
+        let json = serde_json::to_string(cells.as_slice().borrow(&token)).unwrap();
+        assert_eq!("[1,2,42,4]", json);
+
+        let read: Vec<GhostCell<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&[1, 2, 42, 4], read.as_slice().borrow(&token));
+    });
+}
+
+#[derive(Deserialize)]
+struct Node<'brand> {
+    name: String,
+    weight: GhostCell<'brand, i32>,
+    edges: Vec<GhostCell<'brand, usize>>,
+}
+
+#[derive(Serialize)]
+struct NodeView<'a> {
+    name: &'a str,
+    weight: &'a i32,
+    edges: &'a [usize],
+}
+
+impl<'brand> Node<'brand> {
+    fn view<'a>(&'a self, token: &'a GhostToken<'brand>) -> NodeView<'a> {
+        NodeView { name: &self.name, weight: self.weight.borrow(token), edges: self.edges.as_slice().borrow(token) }
+    }
+}
+
+#[test]
+fn round_trip_nested() {
+    GhostToken::new(|mut token| {
+        let json = r#"[{"name":"a","weight":1,"edges":[1]},{"name":"b","weight":2,"edges":[0,1]}]"#;
+
+        let graph: Vec<Node> = serde_json::from_str(json).unwrap();
+        assert_eq!(2, graph.len());
+
+        *graph[1].weight.borrow_mut(&mut token) += 40;
+        graph[0].edges[0].replace(0, &mut token);
+
+        let views: Vec<_> = graph.iter().map(|node| node.view(&token)).collect();
+        let written = serde_json::to_string(&views).unwrap();
+        assert_eq!(r#"[{"name":"a","weight":1,"edges":[0]},{"name":"b","weight":42,"edges":[0,1]}]"#, written);
+
+        let reread: Vec<Node> = serde_json::from_str(&written).unwrap();
+        assert_eq!(42, *reread[1].weight.borrow(&token));
+        assert_eq!(&[0, 1], reread[1].edges.as_slice().borrow(&token));
+    });
+}
+
+#[test]
+fn deserialize_into_existing_cells() {
+    GhostToken::new(|mut token| {
+        let arena: Vec<GhostCell<Vec<String>>> = (0..3).map(|_| GhostCell::default()).collect();
+
+        let json = serde_json::to_string(&["ghost", "cell"]).unwrap();
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        arena[2].deserialize_seed(&mut token).deserialize(&mut deserializer).unwrap();
+        deserializer.end().unwrap();
+
+        assert!(arena[0].borrow(&token).is_empty());
+        assert_eq!(&["ghost", "cell"], &arena[2].borrow(&token)[..]);
+
+        //  On error, the cell is left untouched.
+        let mut deserializer = serde_json::Deserializer::from_str("42");
+        assert!(arena[2].deserialize_seed(&mut token).deserialize(&mut deserializer).is_err());
+        assert_eq!(2, arena[2].borrow(&token).len());
+    });
+}